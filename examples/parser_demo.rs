@@ -1,4 +1,4 @@
-use bytestr::ByteStr;
+use bytestr::{ByteStr, ByteStrCursor};
 
 /// A simple HTTP request parser example demonstrating how to use ByteStr's convenience methods for zero-copy parsing
 fn main() {
@@ -100,47 +100,43 @@ fn main() {
 
     println!("\n=== Lexical Analysis Example ===");
 
-    // Simple lexical analysis
-    let code = ByteStr::from("let x = 42; // a variable");
-    let mut remaining = code;
+    // Simple lexical analysis with a cursor, which keeps track of positions
+    let mut cursor = ByteStrCursor::new(ByteStr::from("let x = 42; // a variable"));
 
-    while !remaining.is_empty() {
+    loop {
         // Skip whitespace characters
-        remaining = remaining.skip_while(|c| c.is_whitespace());
+        cursor.take_while(char::is_whitespace);
 
-        if remaining.is_empty() {
+        let location = cursor.location();
+        let Some(next) = cursor.peek_char() else {
             break;
-        }
+        };
 
         // Check for comments
-        if remaining.as_str().starts_with("//") {
-            let comment = remaining.take_until("\n");
-            println!("Comment: {}", comment.as_str());
-            break;
+        if let Some(marker) = cursor.eat("//") {
+            let comment = cursor.take_until('\n');
+            println!(
+                "Comment at {location}: {}{}",
+                marker.as_str(),
+                comment.as_str()
+            );
         }
-
         // Parse identifiers
-        if remaining.as_str().chars().next().unwrap().is_alphabetic() {
-            let (identifier, rest) = remaining.take_while(|c| c.is_alphanumeric() || c == '_');
-            println!("Identifier: {}", identifier.as_str());
-            remaining = rest;
+        else if next.is_alphabetic() {
+            let identifier = cursor.take_while(|c| c.is_alphanumeric() || c == '_');
+            println!("Identifier at {location}: {}", identifier.as_str());
         }
         // Parse numbers
-        else if remaining.as_str().chars().next().unwrap().is_ascii_digit() {
-            let (number, rest) = remaining.take_while(|c| c.is_ascii_digit());
-            println!("Number: {}", number.as_str());
-            remaining = rest;
+        else if next.is_ascii_digit() {
+            let number = cursor.take_while(|c| c.is_ascii_digit());
+            println!("Number at {location}: {}", number.as_str());
         }
         // Parse operators
-        else {
-            let (operator, rest) = remaining.take_while(|c| "=;".contains(c));
-            if !operator.is_empty() {
-                println!("Operator: {}", operator.as_str());
-                remaining = rest;
-            } else {
-                // Skip one character
-                remaining = remaining.skip(1);
-            }
+        else if let Some(operator) = cursor.eat(['=', ';']) {
+            println!("Operator at {location}: {}", operator.as_str());
+        } else {
+            // Skip one character
+            cursor.next_char();
         }
     }
 }
@@ -0,0 +1,219 @@
+use alloc::collections::VecDeque;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+use crate::{ByteRope, ByteStr};
+
+/// Reads the string as bytes, consuming it from the front.
+///
+/// # Panics
+///
+/// A `ByteStr` must stay valid UTF-8, so [`advance`](Buf::advance) panics if it
+/// would stop inside a multi-byte character, as do
+/// [`copy_to_bytes`](Buf::copy_to_bytes) and the readers built on them, such as
+/// [`get_u8`](Buf::get_u8) on a string starting with `é`. When the reader may
+/// stop at any byte, such as a socket accepting a partial write, use a
+/// [`ByteStrBuf`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Buf;
+/// use bytestr::ByteStr;
+///
+/// let mut s = ByteStr::from("HTTP/1.1 200 OK");
+/// assert_eq!(s.copy_to_bytes(8).as_ref(), b"HTTP/1.1");
+/// assert_eq!(s.get_u8(), b' ');
+/// assert_eq!(s, "200 OK");
+/// ```
+impl Buf for ByteStr {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[track_caller]
+    fn advance(&mut self, cnt: usize) {
+        *self = self.slice(cnt..);
+    }
+
+    #[track_caller]
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        let head = self.slice(..len);
+        self.advance(len);
+        head.into_bytes()
+    }
+}
+
+/// A queue of [`ByteStr`] segments read as a single [`Buf`].
+///
+/// This hands a list of strings to a vectored write without joining them: with
+/// the `std` feature enabled, [`chunks_vectored`](Buf::chunks_vectored) yields
+/// one `IoSlice` per segment. Unlike the `Buf` implementation of `ByteStr`
+/// itself, the queue can be advanced by any number of bytes, including into the
+/// middle of a character, as the segments are kept intact and only an offset
+/// into the first one is tracked.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Buf;
+/// use bytestr::{ByteStr, ByteStrBuf};
+///
+/// let mut buf = ByteStrBuf::new();
+/// buf.push(ByteStr::from_static("HTTP/1.1 200 OK\r\n"));
+/// buf.push(ByteStr::from("content-length: 2\r\n\r\n"));
+/// buf.push(ByteStr::from("ok"));
+/// assert_eq!(buf.remaining(), 40);
+///
+/// // A partial write only consumed part of the first segment
+/// buf.advance(9);
+/// assert_eq!(buf.chunk(), b"200 OK\r\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ByteStrBuf {
+    segments: VecDeque<ByteStr>,
+    // Bytes already consumed from the front segment.
+    offset: usize,
+    remaining: usize,
+}
+
+impl ByteStrBuf {
+    /// Creates an empty queue.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments: VecDeque::new(),
+            offset: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Appends a segment to the end of the queue without copying it.
+    pub fn push(&mut self, segment: ByteStr) {
+        if !segment.is_empty() {
+            self.remaining += segment.len();
+            self.segments.push_back(segment);
+        }
+    }
+
+    /// Returns `true` if all bytes have been consumed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the number of segments with unconsumed bytes left.
+    #[must_use]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Removes all segments from the queue.
+    pub fn clear(&mut self) {
+        self.segments.clear();
+        self.offset = 0;
+        self.remaining = 0;
+    }
+}
+
+impl Buf for ByteStrBuf {
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.segments
+            .front()
+            .map_or(&[], |segment| &segment.as_bytes()[self.offset..])
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut filled = 0;
+        for (slot, segment) in dst.iter_mut().zip(&self.segments) {
+            let offset = if filled == 0 { self.offset } else { 0 };
+            *slot = IoSlice::new(&segment.as_bytes()[offset..]);
+            filled += 1;
+        }
+        filled
+    }
+
+    #[track_caller]
+    fn advance(&mut self, mut cnt: usize) {
+        assert!(
+            cnt <= self.remaining,
+            "cannot advance past `remaining`: {cnt} <= {}",
+            self.remaining
+        );
+        self.remaining -= cnt;
+        while let Some(front) = self.segments.front() {
+            let available = front.len() - self.offset;
+            if cnt < available {
+                self.offset += cnt;
+                return;
+            }
+            cnt -= available;
+            self.segments.pop_front();
+            self.offset = 0;
+        }
+    }
+
+    #[track_caller]
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        assert!(
+            len <= self.remaining,
+            "`len` greater than remaining: {len} <= {}",
+            self.remaining
+        );
+        // Within the front segment, the bytes can be shared instead of copied.
+        if let Some(front) = self.segments.front() {
+            if len <= front.len() - self.offset {
+                let bytes = front
+                    .clone()
+                    .into_bytes()
+                    .slice(self.offset..self.offset + len);
+                self.advance(len);
+                return bytes;
+            }
+        }
+
+        let mut bytes = BytesMut::with_capacity(len);
+        bytes.put((&mut *self).take(len));
+        bytes.freeze()
+    }
+}
+
+impl From<ByteStr> for ByteStrBuf {
+    fn from(segment: ByteStr) -> Self {
+        let mut buf = Self::new();
+        buf.push(segment);
+        buf
+    }
+}
+
+impl From<ByteRope> for ByteStrBuf {
+    fn from(rope: ByteRope) -> Self {
+        rope.segments().iter().cloned().collect()
+    }
+}
+
+impl FromIterator<ByteStr> for ByteStrBuf {
+    fn from_iter<I: IntoIterator<Item = ByteStr>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl Extend<ByteStr> for ByteStrBuf {
+    fn extend<I: IntoIterator<Item = ByteStr>>(&mut self, iter: I) {
+        for segment in iter {
+            self.push(segment);
+        }
+    }
+}
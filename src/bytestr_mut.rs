@@ -0,0 +1,490 @@
+use alloc::borrow::Borrow;
+use alloc::string::String;
+use bytes::{Bytes, BytesMut};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::str::Utf8Error;
+
+use crate::ByteStr;
+
+/// A unique reference to a contiguous, growable UTF-8 encoded string buffer.
+///
+/// `ByteStrMut` is to [`ByteStr`] what [`bytes::BytesMut`] is to [`bytes::Bytes`]:
+/// it can be appended to and split while guaranteeing that its content stays valid
+/// UTF-8, and then frozen into an immutable [`ByteStr`] without copying.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::ByteStrMut;
+/// use core::fmt::Write;
+///
+/// let mut buf = ByteStrMut::with_capacity(64);
+/// buf.push_str("HTTP/1.1 ");
+/// write!(buf, "{} {}", 200, "OK").unwrap();
+/// buf.push('\r');
+/// buf.push('\n');
+///
+/// let line = buf.freeze();
+/// assert_eq!(line, "HTTP/1.1 200 OK\r\n");
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByteStrMut(BytesMut);
+
+impl ByteStrMut {
+    /// Creates a new, empty `ByteStrMut`.
+    ///
+    /// This does not allocate until data is pushed into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let buf = ByteStrMut::new();
+    /// assert!(buf.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self(BytesMut::new())
+    }
+
+    /// Creates a new, empty `ByteStrMut` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let buf = ByteStrMut::with_capacity(64);
+    /// assert!(buf.is_empty());
+    /// assert!(buf.capacity() >= 64);
+    /// ```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(BytesMut::with_capacity(capacity))
+    }
+
+    /// Converts a `BytesMut` to a `ByteStrMut`, validating that it is UTF-8.
+    ///
+    /// The existing allocation is reused, so no copying will happen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let buf = ByteStrMut::from_utf8(BytesMut::from("hello")).unwrap();
+    /// assert_eq!(buf.as_str(), "hello");
+    ///
+    /// assert!(ByteStrMut::from_utf8(BytesMut::from(&[0xFF][..])).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided bytes are not valid UTF-8.
+    pub fn from_utf8(bytes: BytesMut) -> Result<Self, Utf8Error> {
+        match core::str::from_utf8(&bytes) {
+            Ok(_) => Ok(unsafe { Self::from_utf8_unchecked(bytes) }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a `ByteStrMut` from a `BytesMut` without UTF-8 validation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are valid UTF-8.
+    #[must_use]
+    pub const unsafe fn from_utf8_unchecked(bytes: BytesMut) -> Self {
+        Self(bytes)
+    }
+
+    /// Converts `self` into an immutable [`ByteStr`].
+    ///
+    /// The conversion is zero-copy: the returned `ByteStr` shares the buffer
+    /// that was written into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::new();
+    /// buf.push_str("frozen");
+    /// let s = buf.freeze();
+    /// assert_eq!(s.as_str(), "frozen");
+    /// ```
+    #[must_use]
+    pub fn freeze(self) -> ByteStr {
+        unsafe { ByteStr::from_buffer_unchecked(self.0.capacity(), self.0.freeze()) }
+    }
+
+    /// Unwraps the `ByteStrMut` into the inner `BytesMut` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let buf = ByteStrMut::from("hello");
+    /// assert_eq!(&buf.into_bytes_mut()[..], b"hello");
+    /// ```
+    #[must_use]
+    pub fn into_bytes_mut(self) -> BytesMut {
+        self.0
+    }
+
+    /// Extracts a string slice containing the entire buffer.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Extracts a mutable string slice containing the entire buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::from("hello");
+    /// buf.as_mut_str().make_ascii_uppercase();
+    /// assert_eq!(buf.as_str(), "HELLO");
+    /// ```
+    #[must_use]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { core::str::from_utf8_unchecked_mut(&mut self.0) }
+    }
+
+    /// Provides a reference to the inner `BytesMut` object.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &BytesMut {
+        &self.0
+    }
+
+    /// Provides a mutable reference to the inner `BytesMut` object.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the content of the buffer is valid UTF-8
+    /// before the borrow ends and the `ByteStrMut` is used.
+    pub const unsafe fn as_bytes_mut(&mut self) -> &mut BytesMut {
+        &mut self.0
+    }
+
+    /// Returns the length of this `ByteStrMut` in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the `ByteStrMut` has a length of zero bytes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of bytes the `ByteStrMut` can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes.
+    ///
+    /// See [`BytesMut::reserve`] for details on how the existing allocation may be reclaimed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::new();
+    /// buf.reserve(16);
+    /// assert!(buf.capacity() >= 16);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Appends the given `char` to the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::new();
+    /// buf.push('世');
+    /// buf.push('!');
+    /// assert_eq!(buf.as_str(), "世!");
+    /// ```
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends the given string slice to the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::from("Hello");
+    /// buf.push_str(", world!");
+    /// assert_eq!(buf.as_str(), "Hello, world!");
+    /// ```
+    pub fn push_str(&mut self, s: &str) {
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    /// Shortens the buffer, keeping the first `len` bytes and dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the buffer's current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not on a UTF-8 code point boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.as_str().is_char_boundary(len));
+            self.0.truncate(len);
+        }
+    }
+
+    /// Removes all contents of the buffer while retaining the capacity.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Splits the buffer into two at the given index.
+    ///
+    /// Afterwards `self` contains bytes `[0, at)`, and the returned `ByteStrMut`
+    /// contains bytes `[at, len)`. This is an O(1) operation that just increases
+    /// the reference count and sets a few indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is not on a UTF-8 code point boundary, or if it is beyond
+    /// the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut a = ByteStrMut::from("hello 世界");
+    /// let b = a.split_off(6);
+    /// assert_eq!(a.as_str(), "hello ");
+    /// assert_eq!(b.as_str(), "世界");
+    /// ```
+    #[must_use = "consider ByteStrMut::truncate if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(self.as_str().is_char_boundary(at));
+        Self(self.0.split_off(at))
+    }
+
+    /// Splits the buffer into two at the given index.
+    ///
+    /// Afterwards `self` contains bytes `[at, len)`, and the returned `ByteStrMut`
+    /// contains bytes `[0, at)`. This is an O(1) operation that just increases
+    /// the reference count and sets a few indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is not on a UTF-8 code point boundary, or if it is beyond
+    /// the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut a = ByteStrMut::from("key=value");
+    /// let key = a.split_to(3);
+    /// assert_eq!(key.as_str(), "key");
+    /// assert_eq!(a.as_str(), "=value");
+    /// ```
+    #[must_use = "consider ByteStrMut::clear if you don't need the other half"]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        assert!(self.as_str().is_char_boundary(at));
+        Self(self.0.split_to(at))
+    }
+
+    /// Removes the contents of the buffer and returns them, leaving `self` empty.
+    ///
+    /// This is useful to hand out a finished message while keeping the
+    /// remaining capacity for the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrMut;
+    ///
+    /// let mut buf = ByteStrMut::with_capacity(64);
+    /// buf.push_str("first");
+    /// let first = buf.split().freeze();
+    /// buf.push_str("second");
+    /// assert_eq!(first, "first");
+    /// assert_eq!(buf.as_str(), "second");
+    /// ```
+    #[must_use = "consider ByteStrMut::clear if you don't need the contents"]
+    pub fn split(&mut self) -> Self {
+        Self(self.0.split())
+    }
+}
+
+impl fmt::Debug for ByteStrMut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ByteStrMut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Write for ByteStrMut {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl Deref for ByteStrMut {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl DerefMut for ByteStrMut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl Hash for ByteStrMut {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl AsRef<str> for ByteStrMut {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ByteStrMut {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Borrow<str> for ByteStrMut {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for ByteStrMut {
+    fn from(s: &str) -> Self {
+        Self(BytesMut::from(s))
+    }
+}
+
+impl From<String> for ByteStrMut {
+    fn from(s: String) -> Self {
+        Self(BytesMut::from(Bytes::from(s.into_bytes())))
+    }
+}
+
+impl From<ByteStrMut> for ByteStr {
+    fn from(buf: ByteStrMut) -> Self {
+        buf.freeze()
+    }
+}
+
+impl From<ByteStrMut> for BytesMut {
+    fn from(buf: ByteStrMut) -> Self {
+        buf.into_bytes_mut()
+    }
+}
+
+impl Extend<char> for ByteStrMut {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|ch| self.push(ch));
+    }
+}
+
+impl<'a> Extend<&'a str> for ByteStrMut {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_str(s));
+    }
+}
+
+impl FromIterator<char> for ByteStrMut {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl<'a> FromIterator<&'a str> for ByteStrMut {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl PartialEq<str> for ByteStrMut {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ByteStrMut {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for ByteStrMut {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<ByteStrMut> for str {
+    fn eq(&self, other: &ByteStrMut) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialEq<ByteStrMut> for &str {
+    fn eq(&self, other: &ByteStrMut) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialEq<ByteStrMut> for String {
+    fn eq(&self, other: &ByteStrMut) -> bool {
+        other.eq(self)
+    }
+}
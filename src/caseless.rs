@@ -0,0 +1,223 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{ByteStr, hash_in_blocks};
+
+/// A string wrapper whose comparison and hashing ignore ASCII case.
+///
+/// This is meant for keys such as HTTP header names, SMTP verbs or config
+/// keys: `Eq`, `Hash` and `Ord` treat `Content-Type` and `content-type` as the
+/// same key without ever allocating a lowercased copy, while `Display` and
+/// [`get`](Self::get) still give back the original spelling. Non-ASCII
+/// characters are compared exactly.
+///
+/// A map keyed by `CaseInsensitive` can be queried with a plain `&str` through
+/// [`CaseInsensitiveStr`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use bytestr::{ByteStr, CaseInsensitive, CaseInsensitiveStr};
+///
+/// let mut headers = HashMap::new();
+/// headers.insert(CaseInsensitive::new(ByteStr::from("Content-Type")), "text/plain");
+///
+/// assert_eq!(headers[CaseInsensitiveStr::new("content-type")], "text/plain");
+/// let (name, _) = headers.iter().next().unwrap();
+/// assert_eq!(name.to_string(), "Content-Type");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive<T = ByteStr>(T);
+
+impl<T> CaseInsensitive<T> {
+    /// Wraps `value` so that it is compared ignoring ASCII case.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value, in its original spelling.
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the wrapper, returning the wrapped value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> CaseInsensitive<T> {
+    /// Returns the wrapped value as a `&str`, in its original spelling.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// Returns the wrapped value as a [`CaseInsensitiveStr`].
+    #[must_use]
+    pub fn as_caseless(&self) -> &CaseInsensitiveStr {
+        CaseInsensitiveStr::new(self.as_str())
+    }
+}
+
+impl<T> From<T> for CaseInsensitive<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<str>> AsRef<str> for CaseInsensitive<T> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CaseInsensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// Comparison and hashing must agree with `CaseInsensitiveStr`, because of
+// `Borrow<CaseInsensitiveStr>`.
+
+impl<T: AsRef<str>> Borrow<CaseInsensitiveStr> for CaseInsensitive<T> {
+    fn borrow(&self) -> &CaseInsensitiveStr {
+        self.as_caseless()
+    }
+}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialEq<CaseInsensitive<U>> for CaseInsensitive<T> {
+    fn eq(&self, other: &CaseInsensitive<U>) -> bool {
+        self.as_caseless() == other.as_caseless()
+    }
+}
+
+impl<T: AsRef<str>> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<str>> PartialOrd for CaseInsensitive<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for CaseInsensitive<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_caseless().cmp(other.as_caseless())
+    }
+}
+
+impl<T: AsRef<str>> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_caseless().hash(state);
+    }
+}
+
+impl<T: AsRef<str>> PartialEq<str> for CaseInsensitive<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+}
+
+impl<T: AsRef<str>> PartialEq<&str> for CaseInsensitive<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+}
+
+/// A borrowed string slice whose comparison and hashing ignore ASCII case.
+///
+/// This is the borrowed counterpart of [`CaseInsensitive`], like `str` is of
+/// `String`: it lets a map keyed by `CaseInsensitive` be queried with a plain
+/// `&str`, without allocating an owned key.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use bytestr::{ByteStr, CaseInsensitive, CaseInsensitiveStr};
+///
+/// let verbs: BTreeSet<CaseInsensitive> = ["HELO", "MAIL", "QUIT"]
+///     .into_iter()
+///     .map(|verb| CaseInsensitive::new(ByteStr::from_static(verb)))
+///     .collect();
+/// assert!(verbs.contains(CaseInsensitiveStr::new("quit")));
+/// assert_eq!(CaseInsensitiveStr::new("Ehlo"), CaseInsensitiveStr::new("EHLO"));
+/// ```
+#[repr(transparent)]
+pub struct CaseInsensitiveStr(str);
+
+impl CaseInsensitiveStr {
+    /// Views a string slice as a `CaseInsensitiveStr`.
+    #[must_use]
+    pub const fn new(s: &str) -> &Self {
+        // `CaseInsensitiveStr` is a transparent wrapper around `str`.
+        unsafe { &*(core::ptr::from_ref(s) as *const Self) }
+    }
+
+    /// Returns the string slice, in its original spelling.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn folded_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.bytes().map(|b| b.to_ascii_lowercase())
+    }
+}
+
+impl<'a> From<&'a str> for &'a CaseInsensitiveStr {
+    fn from(s: &'a str) -> Self {
+        CaseInsensitiveStr::new(s)
+    }
+}
+
+impl AsRef<str> for CaseInsensitiveStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for CaseInsensitiveStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for CaseInsensitiveStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq for CaseInsensitiveStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for CaseInsensitiveStr {}
+
+impl PartialOrd for CaseInsensitiveStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitiveStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded_bytes().cmp(other.folded_bytes())
+    }
+}
+
+impl Hash for CaseInsensitiveStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_in_blocks(state, self.0.bytes().map(|b| b.to_ascii_lowercase()));
+    }
+}
@@ -0,0 +1,354 @@
+use core::fmt;
+
+use crate::{ByteStr, ExpectError, Pattern};
+
+/// A zero-copy parsing cursor over a [`ByteStr`].
+///
+/// The cursor consumes its input from the front and hands out every token as a
+/// `ByteStr` slice of the original buffer. It keeps track of the byte offset as
+/// well as the line and column it is at, which makes error reporting easy, and
+/// its state can be saved with [`checkpoint`](Self::checkpoint) and restored
+/// with [`rewind`](Self::rewind) to backtrack.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, ByteStrCursor};
+///
+/// let mut cursor = ByteStrCursor::new(ByteStr::from("GET /index.html HTTP/1.1\r\n"));
+/// let method = cursor.take_until(' ');
+/// cursor.expect(' ').unwrap();
+/// let path = cursor.take_until(' ');
+/// cursor.expect(' ').unwrap();
+/// let version = cursor.take_until("\r\n");
+/// cursor.expect("\r\n").unwrap();
+///
+/// assert_eq!(method, "GET");
+/// assert_eq!(path, "/index.html");
+/// assert_eq!(version, "HTTP/1.1");
+/// assert!(cursor.is_empty());
+/// assert_eq!(cursor.location().line(), 2);
+/// ```
+#[derive(Clone)]
+pub struct ByteStrCursor {
+    source: ByteStr,
+    state: Checkpoint,
+}
+
+/// A saved position of a [`ByteStrCursor`], created by [`ByteStrCursor::checkpoint`].
+///
+/// A checkpoint is only meaningful for the cursor that created it (or a clone of it).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkpoint {
+    offset: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl Checkpoint {
+    /// Returns the byte offset the checkpoint was taken at.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// A position in the input of a [`ByteStrCursor`].
+///
+/// Lines and columns are counted from 1; the column counts `char`s, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    pub(crate) const fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the byte offset from the start of the input.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the line number, starting at 1.
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number in `char`s, starting at 1.
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl ByteStrCursor {
+    /// Creates a cursor positioned at the start of `source`.
+    #[must_use]
+    pub const fn new(source: ByteStr) -> Self {
+        Self {
+            source,
+            state: Checkpoint {
+                offset: 0,
+                line: 1,
+                line_start: 0,
+            },
+        }
+    }
+
+    /// Returns the whole input, including the part already consumed.
+    #[must_use]
+    pub const fn source(&self) -> &ByteStr {
+        &self.source
+    }
+
+    /// Returns the unconsumed part of the input as a zero-copy `ByteStr`.
+    #[must_use]
+    pub fn remaining(&self) -> ByteStr {
+        self.source.slice(self.state.offset..)
+    }
+
+    /// Returns the unconsumed part of the input as a `&str`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source.as_str()[self.state.offset..]
+    }
+
+    /// Returns `true` if the whole input has been consumed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.state.offset == self.source.len()
+    }
+
+    /// Returns the current byte offset from the start of the input.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.state.offset
+    }
+
+    /// Returns the current offset together with its line and column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("a = 1\nbé = 2"));
+    /// cursor.take_until("= 2");
+    /// let location = cursor.location();
+    /// assert_eq!((location.line(), location.column()), (2, 4));
+    /// assert_eq!(location.offset(), 10);
+    /// assert_eq!(location.to_string(), "2:4");
+    /// ```
+    #[must_use]
+    pub fn location(&self) -> Location {
+        let line = &self.source.as_str()[self.state.line_start..self.state.offset];
+        Location {
+            offset: self.state.offset,
+            line: self.state.line,
+            column: line.chars().count() + 1,
+        }
+    }
+
+    /// Returns the next byte without consuming it.
+    #[must_use]
+    pub fn peek(&self) -> Option<u8> {
+        self.as_str().as_bytes().first().copied()
+    }
+
+    /// Returns the next `char` without consuming it.
+    #[must_use]
+    pub fn peek_char(&self) -> Option<char> {
+        self.as_str().chars().next()
+    }
+
+    /// Consumes the next `char` and returns it.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.bump(c.len_utf8());
+        Some(c)
+    }
+
+    /// Consumes the next `n` bytes and returns them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` bytes remain, or if the new position is not on
+    /// a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("世界"));
+    /// assert_eq!(cursor.advance(3), "世");
+    /// assert_eq!(cursor.offset(), 3);
+    /// ```
+    #[track_caller]
+    pub fn advance(&mut self, n: usize) -> ByteStr {
+        let start = self.state.offset;
+        let token = self.source.slice(start..start.saturating_add(n));
+        self.bump(n);
+        token
+    }
+
+    /// Consumes `pat` if the remaining input starts with it, returning the
+    /// matched text. The cursor is left untouched if it does not match.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("-42"));
+    /// assert!(cursor.eat('+').is_none());
+    /// assert_eq!(cursor.eat(['+', '-']).unwrap(), "-");
+    /// assert_eq!(cursor.as_str(), "42");
+    /// ```
+    pub fn eat<P: Pattern>(&mut self, mut pat: P) -> Option<ByteStr> {
+        let rest = pat.strip_prefix_of(self.as_str())?;
+        let n = self.as_str().len() - rest.len();
+        Some(self.advance(n))
+    }
+
+    /// Consumes `pat`, which the remaining input must start with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExpectError`] recording the current location and the
+    /// character found there if the input does not start with `pat`. The
+    /// cursor is left untouched in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("key value"));
+    /// cursor.take_until(' ');
+    /// let err = cursor.expect('=').unwrap_err();
+    /// assert_eq!(err.found(), Some(' '));
+    /// assert_eq!(err.location().column(), 4);
+    /// ```
+    pub fn expect<P: Pattern>(&mut self, pat: P) -> Result<ByteStr, ExpectError> {
+        self.eat(pat)
+            .ok_or_else(|| ExpectError::new(self.location(), self.peek_char()))
+    }
+
+    /// Consumes everything up to the first match of `pat`, which is left in
+    /// the input. Consumes the rest of the input if there is no match.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`].
+    pub fn take_until<P: Pattern>(&mut self, mut pat: P) -> ByteStr {
+        let n = pat
+            .find_in(self.as_str())
+            .map_or(self.as_str().len(), |(start, _)| start);
+        self.advance(n)
+    }
+
+    /// Consumes characters while they match `predicate`, returning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("123abc"));
+    /// assert_eq!(cursor.take_while(|c| c.is_ascii_digit()), "123");
+    /// assert_eq!(cursor.take_while(|c| c.is_ascii_digit()), "");
+    /// assert_eq!(cursor.as_str(), "abc");
+    /// ```
+    pub fn take_while<F>(&mut self, mut predicate: F) -> ByteStr
+    where
+        F: FnMut(char) -> bool,
+    {
+        let n = self
+            .as_str()
+            .find(|c| !predicate(c))
+            .unwrap_or(self.as_str().len());
+        self.advance(n)
+    }
+
+    /// Consumes the rest of the input and returns it.
+    pub fn take_rest(&mut self) -> ByteStr {
+        self.advance(self.as_str().len())
+    }
+
+    /// Saves the current position so it can be restored with [`rewind`](Self::rewind).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrCursor};
+    ///
+    /// let mut cursor = ByteStrCursor::new(ByteStr::from("0x1F"));
+    /// let start = cursor.checkpoint();
+    /// if cursor.eat("0b").is_none() {
+    ///     cursor.rewind(start);
+    /// }
+    /// assert_eq!(cursor.eat("0x").unwrap(), "0x");
+    /// ```
+    #[must_use]
+    pub const fn checkpoint(&self) -> Checkpoint {
+        self.state
+    }
+
+    /// Restores a position previously saved with [`checkpoint`](Self::checkpoint).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint lies outside of the input, which can only
+    /// happen if it was taken from a cursor over a different input.
+    #[track_caller]
+    pub fn rewind(&mut self, checkpoint: Checkpoint) {
+        assert!(
+            self.source.as_str().is_char_boundary(checkpoint.offset),
+            "checkpoint does not belong to this cursor"
+        );
+        self.state = checkpoint;
+    }
+
+    /// Moves the position `n` bytes forward, updating the line bookkeeping.
+    fn bump(&mut self, n: usize) {
+        let start = self.state.offset;
+        let consumed = &self.source.as_str()[start..start + n];
+        if let Some(last) = consumed.rfind('\n') {
+            self.state.line += consumed.matches('\n').count();
+            self.state.line_start = start + last + 1;
+        }
+        self.state.offset += n;
+    }
+}
+
+impl From<ByteStr> for ByteStrCursor {
+    fn from(source: ByteStr) -> Self {
+        Self::new(source)
+    }
+}
+
+impl fmt::Debug for ByteStrCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteStrCursor")
+            .field("remaining", &self.as_str())
+            .field("offset", &self.state.offset)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,382 @@
+use alloc::boxed::Box;
+use bytes::Bytes;
+use core::fmt;
+
+use crate::ByteStr;
+
+/// An incremental UTF-8 decoder for chunked input.
+///
+/// Network reads rarely line up with code point boundaries, so a multi-byte
+/// character may be split between two chunks. `Utf8Decoder` validates each chunk
+/// as it arrives, hands out the valid part as zero-copy [`ByteStr`] slices of the
+/// chunk, and carries an incomplete trailing code point (at most three bytes) over
+/// to the next call.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::Utf8Decoder;
+///
+/// let mut decoder = Utf8Decoder::new();
+/// let text = "Hello, 世界!".as_bytes();
+///
+/// // "世" is split across the two chunks
+/// let first = decoder.decode(&text[..8]).unwrap();
+/// let second = decoder.decode(&text[8..]).unwrap();
+/// decoder.finish().unwrap();
+///
+/// let pieces: Vec<_> = first.chain(second).collect();
+/// assert_eq!(pieces, ["Hello, ", "世", "界!"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Utf8Decoder {
+    partial: [u8; 4],
+    partial_len: usize,
+    position: u64,
+}
+
+impl Utf8Decoder {
+    /// Creates a new decoder positioned at the start of a stream.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            partial: [0; 4],
+            partial_len: 0,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes consumed from the stream so far, including
+    /// the bytes of a pending incomplete code point.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the bytes of the incomplete code point carried over from the
+    /// previous chunk, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::Utf8Decoder;
+    ///
+    /// let mut decoder = Utf8Decoder::new();
+    /// decoder.decode(&"世".as_bytes()[..2]).unwrap();
+    /// assert_eq!(decoder.pending(), &"世".as_bytes()[..2]);
+    /// ```
+    #[must_use]
+    pub fn pending(&self) -> &[u8] {
+        &self.partial[..self.partial_len]
+    }
+
+    /// Decodes the next chunk of the stream.
+    ///
+    /// On success, the returned [`Decoded`] yields the valid UTF-8 contained in
+    /// the chunk. The body is a zero-copy slice of `chunk`; only a code point that
+    /// was split with the previous chunk is copied into a small separate piece.
+    /// Trailing bytes of an incomplete code point are kept for the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use bytestr::Utf8Decoder;
+    ///
+    /// let mut decoder = Utf8Decoder::new();
+    /// let decoded = decoder.decode(Bytes::from_static(b"plain ascii")).unwrap();
+    /// assert_eq!(decoded.body(), "plain ascii");
+    /// assert!(decoded.head().is_none());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream contains an invalid UTF-8 sequence. The error
+    /// carries the valid data preceding the invalid sequence, the absolute stream
+    /// offset of the invalid sequence, and the unprocessed rest of the chunk. The
+    /// decoder is left positioned after the invalid sequence, so decoding may be
+    /// resumed by feeding [`Utf8DecodeError::into_remaining`] back in.
+    pub fn decode(&mut self, chunk: impl Into<Bytes>) -> Result<Decoded, Utf8DecodeError> {
+        let chunk = chunk.into();
+        let mut start = 0;
+        let mut head = None;
+
+        if self.partial_len > 0 {
+            let needed = utf8_width(self.partial[0]);
+            let taken = (needed - self.partial_len).min(chunk.len());
+            self.partial[self.partial_len..self.partial_len + taken]
+                .copy_from_slice(&chunk[..taken]);
+            let filled = self.partial_len + taken;
+
+            match core::str::from_utf8(&self.partial[..filled]) {
+                Ok(s) => {
+                    head = Some(ByteStr::copy_from_str(s));
+                    self.partial_len = 0;
+                    self.position += taken as u64;
+                    start = taken;
+                }
+                Err(e) => match e.error_len() {
+                    None => {
+                        // Still incomplete: the whole chunk was absorbed.
+                        self.partial_len = filled;
+                        self.position += taken as u64;
+                        return Ok(Decoded::default());
+                    }
+                    Some(invalid_len) => {
+                        let carried = self.partial_len;
+                        let offset = self.position - carried as u64;
+                        let consumed = invalid_len.saturating_sub(carried);
+                        let invalid = Bytes::copy_from_slice(&self.partial[..invalid_len]);
+                        self.partial_len = 0;
+                        self.position += consumed as u64;
+                        return Err(Utf8DecodeError::new(ErrorInner {
+                            valid: Decoded::default(),
+                            offset,
+                            invalid,
+                            remaining: chunk.slice(consumed..),
+                            incomplete: false,
+                        }));
+                    }
+                },
+            }
+        }
+
+        let rest = &chunk[start..];
+        match core::str::from_utf8(rest) {
+            Ok(_) => {
+                self.position += rest.len() as u64;
+                Ok(Decoded {
+                    head,
+                    body: unsafe { ByteStr::from_utf8_unchecked(chunk.slice(start..)) },
+                })
+            }
+            Err(e) => {
+                let valid_end = start + e.valid_up_to();
+                let body = unsafe { ByteStr::from_utf8_unchecked(chunk.slice(start..valid_end)) };
+                self.position += e.valid_up_to() as u64;
+
+                match e.error_len() {
+                    None => {
+                        let tail = &chunk[valid_end..];
+                        self.partial[..tail.len()].copy_from_slice(tail);
+                        self.partial_len = tail.len();
+                        self.position += tail.len() as u64;
+                        Ok(Decoded { head, body })
+                    }
+                    Some(invalid_len) => {
+                        let offset = self.position;
+                        self.position += invalid_len as u64;
+                        Err(Utf8DecodeError::new(ErrorInner {
+                            valid: Decoded { head, body },
+                            offset,
+                            invalid: chunk.slice(valid_end..valid_end + invalid_len),
+                            remaining: chunk.slice(valid_end + invalid_len..),
+                            incomplete: false,
+                        }))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Signals the end of the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream ended in the middle of a code point. The
+    /// pending bytes are discarded, so the decoder can be reused afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::Utf8Decoder;
+    ///
+    /// let mut decoder = Utf8Decoder::new();
+    /// decoder.decode(&[b'a', 0xE4][..]).unwrap();
+    /// let err = decoder.finish().unwrap_err();
+    /// assert!(err.is_incomplete());
+    /// assert_eq!(err.offset(), 1);
+    /// ```
+    pub fn finish(&mut self) -> Result<(), Utf8DecodeError> {
+        if self.partial_len == 0 {
+            return Ok(());
+        }
+
+        let invalid = Bytes::copy_from_slice(self.pending());
+        let offset = self.position - self.partial_len as u64;
+        self.partial_len = 0;
+        Err(Utf8DecodeError::new(ErrorInner {
+            valid: Decoded::default(),
+            offset,
+            invalid,
+            remaining: Bytes::new(),
+            incomplete: true,
+        }))
+    }
+}
+
+/// The valid UTF-8 produced by a single [`Utf8Decoder::decode`] call.
+///
+/// It consists of an optional head, which is the code point completed from the
+/// previous chunk, followed by a zero-copy body sliced from the current chunk.
+/// Iterating over it yields the non-empty pieces in stream order.
+#[derive(Debug, Clone, Default)]
+pub struct Decoded {
+    head: Option<ByteStr>,
+    body: ByteStr,
+}
+
+impl Decoded {
+    /// Returns the code point completed from the bytes carried over from the previous chunk.
+    #[must_use]
+    pub const fn head(&self) -> Option<&ByteStr> {
+        self.head.as_ref()
+    }
+
+    /// Returns the valid UTF-8 sliced from the current chunk.
+    #[must_use]
+    pub const fn body(&self) -> &ByteStr {
+        &self.body
+    }
+
+    /// Returns the total length of the decoded data in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.head.as_ref().map_or(0, ByteStr::len) + self.body.len()
+    }
+
+    /// Returns `true` if no data was decoded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Merges the decoded data into a single `ByteStr`.
+    ///
+    /// This is zero-copy unless a head is present, in which case the head and
+    /// body are copied into a new buffer.
+    #[must_use]
+    pub fn into_byte_str(self) -> ByteStr {
+        match self.head {
+            None => self.body,
+            Some(head) if self.body.is_empty() => head,
+            Some(head) => {
+                let mut buf = crate::ByteStrMut::with_capacity(head.len() + self.body.len());
+                buf.push_str(&head);
+                buf.push_str(&self.body);
+                buf.freeze()
+            }
+        }
+    }
+}
+
+impl Iterator for Decoded {
+    type Item = ByteStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(head) = self.head.take() {
+            return Some(head);
+        }
+        if self.body.is_empty() {
+            None
+        } else {
+            Some(core::mem::take(&mut self.body))
+        }
+    }
+}
+
+/// An error returned by [`Utf8Decoder`] when the stream is not valid UTF-8.
+#[derive(Debug, Clone)]
+pub struct Utf8DecodeError(Box<ErrorInner>);
+
+#[derive(Debug, Clone)]
+struct ErrorInner {
+    valid: Decoded,
+    offset: u64,
+    invalid: Bytes,
+    remaining: Bytes,
+    incomplete: bool,
+}
+
+impl Utf8DecodeError {
+    fn new(inner: ErrorInner) -> Self {
+        Self(Box::new(inner))
+    }
+
+    /// Returns the absolute stream offset of the first byte of the invalid sequence.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.0.offset
+    }
+
+    /// Returns the bytes of the invalid (or incomplete) sequence.
+    #[must_use]
+    pub fn invalid_bytes(&self) -> &[u8] {
+        &self.0.invalid
+    }
+
+    /// Returns `true` if the error was caused by the stream ending in the middle
+    /// of a code point rather than by an invalid byte.
+    #[must_use]
+    pub const fn is_incomplete(&self) -> bool {
+        self.0.incomplete
+    }
+
+    /// Returns the valid data of the chunk that preceded the invalid sequence.
+    #[must_use]
+    pub const fn valid(&self) -> &Decoded {
+        &self.0.valid
+    }
+
+    /// Returns the valid data of the chunk that preceded the invalid sequence,
+    /// consuming the error.
+    #[must_use]
+    pub fn into_valid(self) -> Decoded {
+        self.0.valid
+    }
+
+    /// Returns the unprocessed rest of the chunk following the invalid sequence.
+    #[must_use]
+    pub const fn remaining(&self) -> &Bytes {
+        &self.0.remaining
+    }
+
+    /// Returns the unprocessed rest of the chunk following the invalid sequence,
+    /// consuming the error.
+    #[must_use]
+    pub fn into_remaining(self) -> Bytes {
+        self.0.remaining
+    }
+}
+
+impl fmt::Display for Utf8DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_incomplete() {
+            write!(
+                f,
+                "incomplete utf-8 byte sequence from stream offset {}",
+                self.0.offset
+            )
+        } else {
+            write!(
+                f,
+                "invalid utf-8 sequence of {} bytes from stream offset {}",
+                self.0.invalid.len(),
+                self.0.offset
+            )
+        }
+    }
+}
+
+impl core::error::Error for Utf8DecodeError {}
+
+/// Returns the length of the UTF-8 sequence introduced by `first`, or 1 for
+/// bytes that cannot start a sequence.
+const fn utf8_width(first: u8) -> usize {
+    match first {
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 1,
+    }
+}
@@ -0,0 +1,194 @@
+use bytes::Bytes;
+use core::fmt;
+use core::str::Utf8Error;
+
+use crate::{ByteStr, Location};
+
+/// A possible error value when converting a `Bytes` into a [`ByteStr`].
+///
+/// This is the error type for [`ByteStr::from_utf8`]. Unlike [`Utf8Error`], it
+/// keeps the buffer that failed validation, so the payload can still be logged,
+/// forwarded or decoded lossily without being copied.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::ByteStr;
+///
+/// let err = ByteStr::from_utf8(vec![b'o', b'k', 0xFF]).unwrap_err();
+/// assert_eq!(err.valid_up_to(), 2);
+/// assert_eq!(err.valid_prefix(), "ok");
+/// assert_eq!(err.into_bytes().as_ref(), &[b'o', b'k', 0xFF]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    bytes: Bytes,
+    error: Utf8Error,
+}
+
+impl FromUtf8Error {
+    pub(crate) const fn new(bytes: Bytes, error: Utf8Error) -> Self {
+        Self { bytes, error }
+    }
+
+    /// Returns a reference to the bytes that were attempted to convert to a `ByteStr`.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Returns the bytes that were attempted to convert to a `ByteStr`.
+    ///
+    /// This method is carefully constructed to avoid allocation. It will
+    /// consume the error, moving out the bytes, so that a copy of the bytes
+    /// does not need to be made.
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    /// Fetches a [`Utf8Error`] to get more details about the conversion failure.
+    #[must_use]
+    pub const fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+
+    /// Returns the index in the given bytes up to which valid UTF-8 was verified.
+    ///
+    /// See [`Utf8Error::valid_up_to`] for details.
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        self.error.valid_up_to()
+    }
+
+    /// Returns the valid UTF-8 prefix of the bytes as a zero-copy `ByteStr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let err = ByteStr::from_utf8(b"GET /\xC0".to_vec()).unwrap_err();
+    /// assert_eq!(err.valid_prefix(), "GET /");
+    /// ```
+    #[must_use]
+    pub fn valid_prefix(&self) -> ByteStr {
+        unsafe { ByteStr::from_utf8_unchecked(self.bytes.slice(..self.valid_up_to())) }
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl core::error::Error for FromUtf8Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error type for [`ByteStr::try_slice`].
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, SliceError};
+///
+/// let s = ByteStr::from("世界");
+/// assert_eq!(s.try_slice(1..), Err(SliceError::NotCharBoundary { index: 1 }));
+/// assert_eq!(
+///     s.try_slice(..7),
+///     Err(SliceError::OutOfBounds { start: 0, end: 7, len: 6 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The range ends past the end of the string, or starts after it ends.
+    OutOfBounds {
+        /// The resolved start of the requested range.
+        start: usize,
+        /// The resolved (exclusive) end of the requested range.
+        end: usize,
+        /// The length of the string in bytes.
+        len: usize,
+    },
+    /// One of the range bounds falls inside a multi-byte UTF-8 sequence.
+    NotCharBoundary {
+        /// The offending byte index.
+        index: usize,
+    },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds { start, end, len } if start > end => {
+                write!(
+                    f,
+                    "slice index starts at {start} but ends at {end} (length {len})"
+                )
+            }
+            Self::OutOfBounds { start, end, len } => {
+                write!(
+                    f,
+                    "range {start}..{end} is out of bounds for string of length {len}"
+                )
+            }
+            Self::NotCharBoundary { index } => {
+                write!(f, "byte index {index} is not a char boundary")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SliceError {}
+
+/// The error type for [`ByteStrCursor::expect`](crate::ByteStrCursor::expect).
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, ByteStrCursor};
+///
+/// let mut cursor = ByteStrCursor::new(ByteStr::from("a\nb"));
+/// cursor.take_until('\n');
+/// cursor.expect('\n').unwrap();
+/// let err = cursor.expect(':').unwrap_err();
+/// assert_eq!(err.to_string(), "unexpected character 'b' at 2:1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectError {
+    location: Location,
+    found: Option<char>,
+}
+
+impl ExpectError {
+    pub(crate) const fn new(location: Location, found: Option<char>) -> Self {
+        Self { location, found }
+    }
+
+    /// Returns the location at which the expected pattern was missing.
+    #[must_use]
+    pub const fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the character found instead, or `None` at the end of the input.
+    #[must_use]
+    pub const fn found(&self) -> Option<char> {
+        self.found
+    }
+}
+
+impl fmt::Display for ExpectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.found {
+            Some(c) => write!(f, "unexpected character {c:?} at {}", self.location),
+            None => write!(f, "unexpected end of input at {}", self.location),
+        }
+    }
+}
+
+impl core::error::Error for ExpectError {}
@@ -0,0 +1,143 @@
+use core::fmt::{self, Write};
+
+use crate::{ByteStr, ByteStrMut};
+
+/// Creates a `ByteStr` from string literals, usable in `const` contexts.
+///
+/// The arguments are joined like [`concat!`], so any literal accepted by it
+/// (strings, chars, integers, floats and booleans) or macros expanding to one,
+/// such as `env!`, may be used. The result points to static memory, so no
+/// allocation ever takes place.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, bytestr};
+///
+/// const GREETING: ByteStr = bytestr!("Hello, ", "world", '!');
+/// assert_eq!(GREETING, "Hello, world!");
+/// assert!(GREETING.is_static());
+///
+/// let version = bytestr!("HTTP/", 1, ".", 1);
+/// assert_eq!(version, "HTTP/1.1");
+/// assert!(bytestr!().is_empty());
+/// ```
+#[macro_export]
+macro_rules! bytestr {
+    () => {
+        $crate::ByteStr::new()
+    };
+    ($($literal:expr),+ $(,)?) => {
+        $crate::ByteStr::from_static(::core::concat!($($literal),+))
+    };
+}
+
+/// Creates a `ByteStr` using interpolation of runtime expressions.
+///
+/// This takes the same arguments as [`format!`](alloc::format), but writes
+/// straight into the storage of the resulting `ByteStr` instead of going
+/// through a `String`. See [`ByteStr::from_fmt`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::format_bytestr;
+///
+/// let status = 404;
+/// let line = format_bytestr!("HTTP/1.1 {status} {}", "Not Found");
+/// assert_eq!(line, "HTTP/1.1 404 Not Found");
+/// ```
+#[macro_export]
+macro_rules! format_bytestr {
+    ($($arg:tt)*) => {
+        $crate::ByteStr::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+impl ByteStr {
+    /// Creates a `ByteStr` from pre-compiled format arguments, as produced by
+    /// [`format_args!`]. This is the function behind [`format_bytestr!`](crate::format_bytestr).
+    ///
+    /// Output that has no arguments to format is used as a static string, and
+    /// short output is formatted into inline storage, so neither allocates.
+    /// Longer output is written into a [`ByteStrMut`], which is frozen without
+    /// copying if it is no larger than the output, and compacted otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error, like
+    /// [`format!`](alloc::format) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from_fmt(format_args!("{}-{}", "a", 1));
+    /// assert_eq!(s, "a-1");
+    /// assert!(ByteStr::from_fmt(format_args!("literal")).is_static());
+    /// ```
+    #[must_use]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        if let Some(s) = args.as_str() {
+            return Self::from_static(s);
+        }
+
+        let mut builder = Builder::new();
+        builder
+            .write_fmt(args)
+            .expect("a formatting trait implementation returned an error when the underlying stream did not");
+        builder.finish()
+    }
+}
+
+/// Collects formatted output inline, moving it to a heap buffer only once it
+/// outgrows the inline capacity of `ByteStr`.
+struct Builder {
+    inline: [u8; ByteStr::INLINE_CAPACITY],
+    len: usize,
+    heap: Option<ByteStrMut>,
+}
+
+impl Builder {
+    const fn new() -> Self {
+        Self {
+            inline: [0; ByteStr::INLINE_CAPACITY],
+            len: 0,
+            heap: None,
+        }
+    }
+
+    fn inline_str(&self) -> &str {
+        // Only whole `&str`s are ever copied into the inline buffer.
+        unsafe { core::str::from_utf8_unchecked(&self.inline[..self.len]) }
+    }
+
+    fn finish(self) -> ByteStr {
+        match self.heap {
+            Some(heap) => {
+                let mut s = heap.freeze();
+                s.compact();
+                s
+            }
+            None => ByteStr::copy_from_str(self.inline_str()),
+        }
+    }
+}
+
+impl Write for Builder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Some(heap) = &mut self.heap {
+            heap.push_str(s);
+        } else if let Some(dst) = self.inline.get_mut(self.len..self.len + s.len()) {
+            dst.copy_from_slice(s.as_bytes());
+            self.len += s.len();
+        } else {
+            let mut heap = ByteStrMut::with_capacity(2 * (self.len + s.len()));
+            heap.push_str(self.inline_str());
+            heap.push_str(s);
+            self.heap = Some(heap);
+        }
+        Ok(())
+    }
+}
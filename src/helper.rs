@@ -1,4 +1,10 @@
 use crate::ByteStr;
+use crate::iter::{
+    IntoCharIndices, IntoChars, Lines, MatchIndices, Matches, RMatchIndices, RMatches, RSplit,
+    RSplitN, RSplitTerminator, Split, SplitAsciiWhitespace, SplitInclusive, SplitInternal, SplitN,
+    SplitNInternal, SplitTerminator, SplitWhitespace,
+};
+use crate::pattern::Pattern;
 
 impl ByteStr {
     /// Returns an iterator over the lines of the string, as zero-copy `ByteStr` slices.
@@ -18,8 +24,9 @@ impl ByteStr {
     /// assert_eq!(lines[1].as_str(), "bar");
     /// assert_eq!(lines[2].as_str(), "baz");
     /// ```
-    pub fn lines(&self) -> impl Iterator<Item = Self> {
-        self.as_str().lines().map(|s| self.slice_ref(s))
+    #[must_use]
+    pub fn lines(&self) -> Lines {
+        self.clone().into_lines()
     }
 
     /// Divides one `ByteStr` into two at an index.
@@ -45,16 +52,43 @@ impl ByteStr {
     /// assert_eq!(left.as_str(), "Hello, ");
     /// assert_eq!(right.as_str(), "world!");
     /// ```
+    #[must_use]
     pub fn split_at(self, mid: usize) -> (Self, Self) {
         let left = self.slice_ref(&self.as_str()[..mid]);
         let right = self.slice_ref(&self.as_str()[mid..]);
         (left, right)
     }
 
+    /// Divides one `ByteStr` into two at an index, returning `None` if `mid` is
+    /// out of bounds or not on a UTF-8 code point boundary.
+    ///
+    /// This is the non-panicking version of [`ByteStr::split_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Per Martin-Löf");
+    /// let (first, last) = s.split_at_checked(3).unwrap();
+    /// assert_eq!(first, "Per");
+    /// assert_eq!(last, " Martin-Löf");
+    ///
+    /// assert!(s.split_at_checked(13).is_none()); // inside "ö"
+    /// assert!(s.split_at_checked(16).is_none()); // beyond the string length
+    /// ```
+    #[must_use]
+    pub fn split_at_checked(&self, mid: usize) -> Option<(Self, Self)> {
+        let (left, right) = self.as_str().split_at_checked(mid)?;
+        Some((self.slice_ref(left), self.slice_ref(right)))
+    }
+
     /// Splits a `ByteStr` by a pattern, returning an iterator of zero-copy slices.
     ///
-    /// The pattern can be a `&str`. The iterator returned will yield `ByteStr` instances
-    /// that reference parts of the original string without copying data.
+    /// The [pattern](Pattern) can be a `&str`, `char`, a slice of `char`s, or a
+    /// function or closure that determines if a character matches. The iterator
+    /// returned will yield `ByteStr` instances that reference parts of the original
+    /// string without copying data.
     ///
     /// # Examples
     ///
@@ -67,15 +101,92 @@ impl ByteStr {
     /// assert_eq!(parts[0].as_str(), "hello");
     /// assert_eq!(parts[1].as_str(), "world");
     /// assert_eq!(parts[2].as_str(), "rust");
+    ///
+    /// let s = ByteStr::from("a1b2c");
+    /// let parts: Vec<_> = s.split(|c: char| c.is_ascii_digit()).collect();
+    /// assert_eq!(parts, ["a", "b", "c"]);
+    /// ```
+    pub fn split<P: Pattern>(&self, pat: P) -> Split<P> {
+        self.clone().into_split(pat)
+    }
+
+    /// Splits a `ByteStr` by a pattern, returning an iterator of zero-copy slices
+    /// in reverse order.
+    ///
+    /// The iterator can be consumed from both ends when the pattern matches single
+    /// characters; see [`DoubleEndedPattern`](crate::DoubleEndedPattern).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("lion::tiger::leopard");
+    /// let parts: Vec<_> = s.rsplit("::").collect();
+    /// assert_eq!(parts, ["leopard", "tiger", "lion"]);
+    /// ```
+    pub fn rsplit<P: Pattern>(&self, pat: P) -> RSplit<P> {
+        self.clone().into_rsplit(pat)
+    }
+
+    /// Splits a `ByteStr` by a pattern like [`ByteStr::split`], except that a
+    /// trailing empty substring is skipped.
+    ///
+    /// This is useful for data that is terminated, rather than separated, by the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("A;B;C;");
+    /// let parts: Vec<_> = s.split_terminator(';').collect();
+    /// assert_eq!(parts, ["A", "B", "C"]);
+    /// ```
+    pub fn split_terminator<P: Pattern>(&self, pat: P) -> SplitTerminator<P> {
+        self.clone().into_split_terminator(pat)
+    }
+
+    /// Splits a `ByteStr` by a pattern like [`ByteStr::rsplit`], except that a
+    /// trailing empty substring is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("A;B;C;");
+    /// let parts: Vec<_> = s.rsplit_terminator(';').collect();
+    /// assert_eq!(parts, ["C", "B", "A"]);
+    /// ```
+    pub fn rsplit_terminator<P: Pattern>(&self, pat: P) -> RSplitTerminator<P> {
+        self.clone().into_rsplit_terminator(pat)
+    }
+
+    /// Splits a `ByteStr` by a pattern, keeping each match at the end of the
+    /// substring it terminates.
+    ///
+    /// If the last element of the string is matched, it is considered a terminator
+    /// and no trailing empty substring is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Mary had a little lamb\nlittle lamb\nlittle lamb.\n");
+    /// let lines: Vec<_> = s.split_inclusive('\n').collect();
+    /// assert_eq!(lines, ["Mary had a little lamb\n", "little lamb\n", "little lamb.\n"]);
     /// ```
-    pub fn split(&self, pat: &str) -> impl Iterator<Item = Self> {
-        self.as_str().split(pat).map(move |s| self.slice_ref(s))
+    pub fn split_inclusive<P: Pattern>(&self, pat: P) -> SplitInclusive<P> {
+        self.clone().into_split_inclusive(pat)
     }
 
     /// Splits a `ByteStr` by a pattern, limiting the number of splits.
     ///
-    /// The `n` parameter specifies the maximum number of splits to make.
+    /// The `n` parameter specifies the maximum number of items to return.
     /// The last element of the iterator will contain the remainder of the string.
+    /// The pattern accepts the same types as [`ByteStr::split`].
     ///
     /// # Examples
     ///
@@ -89,14 +200,33 @@ impl ByteStr {
     /// assert_eq!(parts[1].as_str(), "b");
     /// assert_eq!(parts[2].as_str(), "c,d");
     /// ```
-    pub fn splitn(&self, n: usize, pat: &str) -> impl Iterator<Item = Self> {
-        self.as_str().splitn(n, pat).map(move |s| self.slice_ref(s))
+    pub fn splitn<P: Pattern>(&self, n: usize, pat: P) -> SplitN<P> {
+        self.clone().into_splitn(n, pat)
+    }
+
+    /// Splits a `ByteStr` by a pattern starting from the end, limiting the number
+    /// of items returned.
+    ///
+    /// The last element of the iterator will contain the remainder of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("a,b,c,d");
+    /// let parts: Vec<_> = s.rsplitn(2, ',').collect();
+    /// assert_eq!(parts, ["d", "a,b,c"]);
+    /// ```
+    pub fn rsplitn<P: Pattern>(&self, n: usize, pat: P) -> RSplitN<P> {
+        self.clone().into_rsplitn(n, pat)
     }
 
     /// Splits a `ByteStr` on the first occurrence of a pattern.
     ///
     /// Returns `Some((before, after))` if the pattern is found, where both parts
     /// are zero-copy `ByteStr` slices. Returns `None` if the pattern is not found.
+    /// The pattern accepts the same types as [`ByteStr::split`].
     ///
     /// # Examples
     ///
@@ -112,10 +242,31 @@ impl ByteStr {
     /// let s = ByteStr::from("no-equals-sign");
     /// assert!(s.split_once("=").is_none());
     /// ```
-    pub fn split_once(&self, pat: &str) -> Option<(Self, Self)> {
-        self.as_str()
-            .split_once(pat)
-            .map(|(l, r)| (self.slice_ref(l), self.slice_ref(r)))
+    pub fn split_once<P: Pattern>(&self, mut pat: P) -> Option<(Self, Self)> {
+        let s = self.as_str();
+        pat.find_in(s)
+            .map(|(a, b)| (self.slice_ref(&s[..a]), self.slice_ref(&s[b..])))
+    }
+
+    /// Splits a `ByteStr` on the last occurrence of a pattern.
+    ///
+    /// Returns `Some((before, after))` if the pattern is found, where both parts
+    /// are zero-copy `ByteStr` slices. Returns `None` if the pattern is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("archive.tar.gz");
+    /// let (stem, ext) = s.rsplit_once('.').unwrap();
+    /// assert_eq!(stem, "archive.tar");
+    /// assert_eq!(ext, "gz");
+    /// ```
+    pub fn rsplit_once<P: Pattern>(&self, mut pat: P) -> Option<(Self, Self)> {
+        let s = self.as_str();
+        pat.rfind_in(s)
+            .map(|(a, b)| (self.slice_ref(&s[..a]), self.slice_ref(&s[b..])))
     }
 
     /// Splits a `ByteStr` by ASCII whitespace, returning an iterator of zero-copy slices.
@@ -134,14 +285,97 @@ impl ByteStr {
     /// assert_eq!(words[0].as_str(), "hello");
     /// assert_eq!(words[1].as_str(), "world");
     /// ```
-    pub fn split_whitespace(&self) -> impl Iterator<Item = Self> {
-        self.as_str().split_whitespace().map(|s| self.slice_ref(s))
+    #[must_use]
+    pub fn split_whitespace(&self) -> SplitWhitespace {
+        self.clone().into_split_whitespace()
+    }
+
+    /// Splits a `ByteStr` by ASCII whitespace, returning an iterator of zero-copy slices.
+    ///
+    /// Unlike [`ByteStr::split_whitespace`], only the characters matched by
+    /// [`char::is_ascii_whitespace`] are treated as separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from(" Mary\thad\u{A0}a  lamb\n");
+    /// let words: Vec<_> = s.split_ascii_whitespace().collect();
+    /// assert_eq!(words, ["Mary", "had\u{A0}a", "lamb"]);
+    /// ```
+    #[must_use]
+    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace {
+        self.clone().into_split_ascii_whitespace()
+    }
+
+    /// Returns an iterator over the disjoint matches of a pattern, as zero-copy slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("1abc2abc3");
+    /// let digits: Vec<_> = s.matches(char::is_numeric).collect();
+    /// assert_eq!(digits, ["1", "2", "3"]);
+    /// ```
+    pub fn matches<P: Pattern>(&self, pat: P) -> Matches<P> {
+        self.clone().into_matches(pat)
+    }
+
+    /// Returns an iterator over the disjoint matches of a pattern in reverse
+    /// order, as zero-copy slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("abcXXXabcYYYabc");
+    /// assert_eq!(s.rmatches("abc").count(), 3);
+    /// ```
+    pub fn rmatches<P: Pattern>(&self, pat: P) -> RMatches<P> {
+        self.clone().into_rmatches(pat)
+    }
+
+    /// Returns an iterator over the disjoint matches of a pattern, together with
+    /// the byte index at which each match starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("abcXXXabcYYYabc");
+    /// let found: Vec<_> = s.match_indices("abc").map(|(i, _)| i).collect();
+    /// assert_eq!(found, [0, 6, 12]);
+    /// ```
+    pub fn match_indices<P: Pattern>(&self, pat: P) -> MatchIndices<P> {
+        self.clone().into_match_indices(pat)
+    }
+
+    /// Returns an iterator over the disjoint matches of a pattern in reverse
+    /// order, together with the byte index at which each match starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("abcXXXabcYYYabc");
+    /// let found: Vec<_> = s.rmatch_indices("abc").map(|(i, _)| i).collect();
+    /// assert_eq!(found, [12, 6, 0]);
+    /// ```
+    pub fn rmatch_indices<P: Pattern>(&self, pat: P) -> RMatchIndices<P> {
+        self.clone().into_rmatch_indices(pat)
     }
 
     /// Removes a prefix from the string, returning the remainder as a new `ByteStr`.
     ///
     /// If the string starts with the pattern `prefix`, returns `Some` with the remainder
-    /// of the string after the prefix. Otherwise, returns `None`.
+    /// of the string after the prefix. Otherwise, returns `None`. The pattern accepts
+    /// the same types as [`ByteStr::split`].
     ///
     /// # Examples
     ///
@@ -151,17 +385,19 @@ impl ByteStr {
     /// let s = ByteStr::from("foo:bar");
     /// assert_eq!(s.strip_prefix("foo:"), Some(ByteStr::from("bar")));
     /// assert_eq!(s.strip_prefix("bar"), None);
+    /// assert_eq!(s.strip_prefix('f'), Some(ByteStr::from("oo:bar")));
     /// ```
-    pub fn strip_prefix(&self, prefix: &str) -> Option<Self> {
-        self.as_str()
-            .strip_prefix(prefix)
+    pub fn strip_prefix<P: Pattern>(&self, mut prefix: P) -> Option<Self> {
+        prefix
+            .strip_prefix_of(self.as_str())
             .map(|s| self.slice_ref(s))
     }
 
     /// Removes a suffix from the string, returning the remainder as a new `ByteStr`.
     ///
     /// If the string ends with the pattern `suffix`, returns `Some` with the remainder
-    /// of the string before the suffix. Otherwise, returns `None`.
+    /// of the string before the suffix. Otherwise, returns `None`. The pattern accepts
+    /// the same types as [`ByteStr::split`].
     ///
     /// # Examples
     ///
@@ -171,10 +407,11 @@ impl ByteStr {
     /// let s = ByteStr::from("bar:foo");
     /// assert_eq!(s.strip_suffix(":foo"), Some(ByteStr::from("bar")));
     /// assert_eq!(s.strip_suffix("baz"), None);
+    /// assert_eq!(s.strip_suffix(['o', 'x']), Some(ByteStr::from("bar:fo")));
     /// ```
-    pub fn strip_suffix(&self, suffix: &str) -> Option<Self> {
-        self.as_str()
-            .strip_suffix(suffix)
+    pub fn strip_suffix<P: Pattern>(&self, mut suffix: P) -> Option<Self> {
+        suffix
+            .strip_suffix_of(self.as_str())
             .map(|s| self.slice_ref(s))
     }
 
@@ -257,6 +494,26 @@ impl ByteStr {
         self.slice_ref(&self.as_str()[..n])
     }
 
+    /// Takes the first `n` bytes, returning `None` if `n` is out of bounds or
+    /// not on a UTF-8 code point boundary.
+    ///
+    /// This is the non-panicking version of [`ByteStr::take`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("世界");
+    /// assert_eq!(s.checked_take(3).unwrap(), "世");
+    /// assert!(s.checked_take(4).is_none());
+    /// assert!(s.checked_take(7).is_none());
+    /// ```
+    #[must_use]
+    pub fn checked_take(&self, n: usize) -> Option<Self> {
+        self.get(..n)
+    }
+
     /// Skips the first `n` bytes and returns the remainder.
     ///
     /// This operation creates a new `ByteStr` that references the string starting
@@ -282,10 +539,31 @@ impl ByteStr {
         self.slice_ref(&self.as_str()[n..])
     }
 
+    /// Skips the first `n` bytes and returns the remainder, or `None` if `n` is
+    /// out of bounds or not on a UTF-8 code point boundary.
+    ///
+    /// This is the non-panicking version of [`ByteStr::skip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("世界");
+    /// assert_eq!(s.checked_skip(3).unwrap(), "界");
+    /// assert_eq!(s.checked_skip(6).unwrap(), "");
+    /// assert!(s.checked_skip(1).is_none());
+    /// ```
+    #[must_use]
+    pub fn checked_skip(&self, n: usize) -> Option<Self> {
+        self.get(n..)
+    }
+
     /// Takes characters from the start until a pattern is found.
     ///
     /// Returns a `ByteStr` containing everything before the first occurrence of
     /// the pattern. If the pattern is not found, returns a clone of the entire string.
+    /// The pattern accepts the same types as [`ByteStr::split`].
     ///
     /// # Examples
     ///
@@ -301,10 +579,11 @@ impl ByteStr {
     /// assert_eq!(all.as_str(), "Hello world");
     /// ```
     #[must_use]
-    pub fn take_until(&self, pat: &str) -> Self {
-        self.as_str()
-            .find(pat)
-            .map_or_else(|| self.clone(), |pos| self.slice_ref(&self.as_str()[..pos]))
+    pub fn take_until<P: Pattern>(&self, mut pat: P) -> Self {
+        pat.find_in(self.as_str()).map_or_else(
+            || self.clone(),
+            |(pos, _)| self.slice_ref(&self.as_str()[..pos]),
+        )
     }
 
     /// Skips characters from the start while they match a predicate.
@@ -378,3 +657,143 @@ impl ByteStr {
         (taken, remaining)
     }
 }
+
+// Owning constructors: the iterators keep the `ByteStr` they split, so these
+// only differ from the borrowing methods in whether a clone is needed.
+impl ByteStr {
+    /// Returns an iterator over the lines of the string, consuming `self`.
+    ///
+    /// This is the owning counterpart of [`ByteStr::lines`]. The iterator holds
+    /// the `ByteStr` itself, so it can be stored or returned without borrowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, iter::Lines};
+    ///
+    /// fn config_lines() -> Lines {
+    ///     ByteStr::from("port=8080\nhost=localhost\n").into_lines()
+    /// }
+    ///
+    /// let lines: Vec<_> = config_lines().collect();
+    /// assert_eq!(lines, ["port=8080", "host=localhost"]);
+    /// ```
+    #[must_use]
+    pub const fn into_lines(self) -> Lines {
+        Lines::new(self)
+    }
+
+    /// Returns an iterator over the `char`s of the string, consuming `self`.
+    ///
+    /// This is the owning counterpart of [`str::chars`], for iterators that
+    /// have to outlive the string they were created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let mut chars = ByteStr::from("añb").into_chars();
+    /// assert_eq!(chars.next(), Some('a'));
+    /// assert_eq!(chars.next_back(), Some('b'));
+    /// assert_eq!(chars.as_byte_str(), "ñ");
+    /// ```
+    #[must_use]
+    pub const fn into_chars(self) -> IntoChars {
+        IntoChars::new(self)
+    }
+
+    /// Returns an iterator over the `char`s of the string and their byte
+    /// offsets, consuming `self`.
+    ///
+    /// This is the owning counterpart of [`str::char_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let indices: Vec<_> = ByteStr::from("añb").into_char_indices().collect();
+    /// assert_eq!(indices, [(0, 'a'), (1, 'ñ'), (3, 'b')]);
+    /// ```
+    #[must_use]
+    pub const fn into_char_indices(self) -> IntoCharIndices {
+        IntoCharIndices::new(self)
+    }
+
+    /// Splits the string by whitespace, consuming `self`.
+    ///
+    /// This is the owning counterpart of [`ByteStr::split_whitespace`].
+    #[must_use]
+    pub fn into_split_whitespace(self) -> SplitWhitespace {
+        SplitWhitespace::new(self)
+    }
+
+    /// Owning counterpart of [`ByteStr::split`], consuming `self` instead of cloning it.
+    pub const fn into_split<P: Pattern>(self, pat: P) -> Split<P> {
+        let len = self.len();
+        Split::new(self, SplitInternal::new(pat, len, true))
+    }
+
+    /// Owning counterpart of [`ByteStr::rsplit`], consuming `self` instead of cloning it.
+    pub const fn into_rsplit<P: Pattern>(self, pat: P) -> RSplit<P> {
+        let len = self.len();
+        RSplit::new(self, SplitInternal::new(pat, len, true))
+    }
+
+    /// Owning counterpart of [`ByteStr::split_terminator`], consuming `self` instead of cloning it.
+    pub const fn into_split_terminator<P: Pattern>(self, pat: P) -> SplitTerminator<P> {
+        let len = self.len();
+        SplitTerminator::new(self, SplitInternal::new(pat, len, false))
+    }
+
+    /// Owning counterpart of [`ByteStr::rsplit_terminator`], consuming `self` instead of cloning it.
+    pub const fn into_rsplit_terminator<P: Pattern>(self, pat: P) -> RSplitTerminator<P> {
+        let len = self.len();
+        RSplitTerminator::new(self, SplitInternal::new(pat, len, false))
+    }
+
+    /// Owning counterpart of [`ByteStr::split_inclusive`], consuming `self` instead of cloning it.
+    pub const fn into_split_inclusive<P: Pattern>(self, pat: P) -> SplitInclusive<P> {
+        let len = self.len();
+        SplitInclusive::new(self, SplitInternal::new(pat, len, false))
+    }
+
+    /// Owning counterpart of [`ByteStr::splitn`], consuming `self` instead of cloning it.
+    pub const fn into_splitn<P: Pattern>(self, n: usize, pat: P) -> SplitN<P> {
+        let len = self.len();
+        SplitN::new(self, SplitNInternal::new(pat, len, n))
+    }
+
+    /// Owning counterpart of [`ByteStr::rsplitn`], consuming `self` instead of cloning it.
+    pub const fn into_rsplitn<P: Pattern>(self, n: usize, pat: P) -> RSplitN<P> {
+        let len = self.len();
+        RSplitN::new(self, SplitNInternal::new(pat, len, n))
+    }
+
+    /// Owning counterpart of [`ByteStr::split_ascii_whitespace`], consuming `self` instead of cloning it.
+    #[must_use]
+    pub fn into_split_ascii_whitespace(self) -> SplitAsciiWhitespace {
+        SplitAsciiWhitespace::new(self)
+    }
+
+    /// Owning counterpart of [`ByteStr::matches`], consuming `self` instead of cloning it.
+    pub const fn into_matches<P: Pattern>(self, pat: P) -> Matches<P> {
+        Matches::new(self, pat)
+    }
+
+    /// Owning counterpart of [`ByteStr::rmatches`], consuming `self` instead of cloning it.
+    pub const fn into_rmatches<P: Pattern>(self, pat: P) -> RMatches<P> {
+        RMatches::new(self, pat)
+    }
+
+    /// Owning counterpart of [`ByteStr::match_indices`], consuming `self` instead of cloning it.
+    pub const fn into_match_indices<P: Pattern>(self, pat: P) -> MatchIndices<P> {
+        MatchIndices::new(self, pat)
+    }
+
+    /// Owning counterpart of [`ByteStr::rmatch_indices`], consuming `self` instead of cloning it.
+    pub const fn into_rmatch_indices<P: Pattern>(self, pat: P) -> RMatchIndices<P> {
+        RMatchIndices::new(self, pat)
+    }
+}
@@ -1,8 +1,12 @@
 use alloc::borrow::{Borrow, Cow};
 use alloc::string::String;
 use bytes::Bytes;
+use core::cmp::Ordering;
 use core::fmt;
-use core::ops::{Deref, Index, Range, RangeFrom, RangeFull, RangeTo, RangeToInclusive};
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Deref, Index, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
 use core::str::FromStr;
 
 use crate::ByteStr;
@@ -45,17 +49,56 @@ impl AsRef<[u8]> for ByteStr {
     }
 }
 
+impl Default for ByteStr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FromStr for ByteStr {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::from(s))
+        Ok(Self::copy_from_str(s))
     }
 }
 
+// Short strings are stored inline; longer ones reuse the `String`'s buffer.
 impl<T: Into<String>> From<T> for ByteStr {
     fn from(s: T) -> Self {
-        Self(s.into().into_bytes().into())
+        let s = s.into();
+        Self::inline(&s).unwrap_or_else(|| {
+            let backing = s.capacity();
+            unsafe { Self::from_buffer_unchecked(backing, s.into_bytes().into()) }
+        })
+    }
+}
+
+// Comparison and hashing must agree with `str`, because of `Borrow<str>`.
+
+impl PartialEq for ByteStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ByteStr {}
+
+impl PartialOrd for ByteStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for ByteStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
     }
 }
 
@@ -107,6 +150,54 @@ impl PartialEq<ByteStr> for Cow<'_, str> {
     }
 }
 
+impl PartialOrd<str> for ByteStr {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl PartialOrd<String> for ByteStr {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<&str> for ByteStr {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.as_str().partial_cmp(*other)
+    }
+}
+
+impl PartialOrd<Cow<'_, str>> for ByteStr {
+    fn partial_cmp(&self, other: &Cow<str>) -> Option<Ordering> {
+        self.as_str().partial_cmp(&**other)
+    }
+}
+
+impl PartialOrd<ByteStr> for str {
+    fn partial_cmp(&self, other: &ByteStr) -> Option<Ordering> {
+        self.partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<ByteStr> for String {
+    fn partial_cmp(&self, other: &ByteStr) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<ByteStr> for &str {
+    fn partial_cmp(&self, other: &ByteStr) -> Option<Ordering> {
+        (*self).partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<ByteStr> for Cow<'_, str> {
+    fn partial_cmp(&self, other: &ByteStr) -> Option<Ordering> {
+        (**self).partial_cmp(other.as_str())
+    }
+}
+
 impl From<ByteStr> for Bytes {
     fn from(data: ByteStr) -> Self {
         data.into_bytes()
@@ -139,6 +230,14 @@ impl Index<RangeTo<usize>> for ByteStr {
     }
 }
 
+impl Index<RangeInclusive<usize>> for ByteStr {
+    type Output = str;
+
+    fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
+        &self.as_str()[index]
+    }
+}
+
 impl Index<RangeToInclusive<usize>> for ByteStr {
     type Output = str;
 
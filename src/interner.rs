@@ -0,0 +1,389 @@
+use alloc::collections::BTreeSet;
+use bytes::Bytes;
+#[cfg(any(feature = "std", feature = "spin"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(all(feature = "spin", not(feature = "std")))]
+use spin::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::ByteStr;
+
+/// A string interner handing out deduplicated [`ByteStr`] handles.
+///
+/// Interning a string returns a `ByteStr` that shares its buffer with every
+/// other handle interned for the same content, so a key repeated across
+/// millions of records is stored once. Interned handles are always backed by a
+/// shared buffer rather than stored inline, which lets two values from the same
+/// interner be compared with [`ByteStr::ptr_eq`] instead of by content.
+///
+/// Lookups take a plain `&str` and don't allocate; only the first occurrence of
+/// a string is copied into the interner. For bounded memory, the interner can
+/// be given a byte budget with [`with_max_bytes`](Self::with_max_bytes), and
+/// strings no longer used outside of it can be dropped with
+/// [`evict_unused`](Self::evict_unused).
+///
+/// This type is single-threaded; see [`SyncByteStrInterner`] for a version that
+/// can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, ByteStrInterner};
+///
+/// let mut interner = ByteStrInterner::new();
+/// interner.intern_static("content-type");
+///
+/// let a = interner.intern("content-type");
+/// let b = interner.intern(&String::from("content-type"));
+/// assert!(ByteStr::ptr_eq(&a, &b));
+/// assert!(a.is_static());
+///
+/// let stats = interner.stats();
+/// assert_eq!((stats.entries, stats.hits, stats.misses), (1, 2, 0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ByteStrInterner {
+    set: BTreeSet<ByteStr>,
+    bytes: usize,
+    max_bytes: Option<usize>,
+    hits: usize,
+    misses: usize,
+}
+
+/// Statistics about the contents and use of a [`ByteStrInterner`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InternerStats {
+    /// The number of distinct strings held by the interner.
+    pub entries: usize,
+    /// The number of bytes the interner allocated for its strings. Strings
+    /// seeded with [`ByteStrInterner::intern_static`] don't count towards this.
+    pub bytes: usize,
+    /// The number of lookups that found an already interned string.
+    pub hits: usize,
+    /// The number of lookups that had to copy a new string into the interner.
+    pub misses: usize,
+}
+
+impl ByteStrInterner {
+    /// Creates an empty interner without a memory limit.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            set: BTreeSet::new(),
+            bytes: 0,
+            max_bytes: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Creates an empty interner that allocates at most `max_bytes` bytes for
+    /// its strings.
+    ///
+    /// When a new string doesn't fit in the budget, strings that are no longer
+    /// used outside of the interner are evicted to make room. If there is still
+    /// not enough room, the string is returned without being interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, ByteStrInterner};
+    ///
+    /// let mut interner = ByteStrInterner::with_max_bytes(10);
+    /// let host = interner.intern("host");
+    /// let _ = interner.intern("date");
+    ///
+    /// // "date" is no longer used, so it is evicted to make room
+    /// let accept = interner.intern("accept");
+    /// assert!(interner.contains("accept"));
+    /// assert!(!interner.contains("date"));
+    ///
+    /// // both `host` and `accept` are still in use, so this one can't be interned
+    /// let cookie = interner.intern("cookie");
+    /// assert_eq!(cookie, "cookie");
+    /// assert!(!interner.contains("cookie"));
+    /// assert!(ByteStr::ptr_eq(&host, &interner.intern("host")));
+    /// ```
+    #[must_use]
+    pub const fn with_max_bytes(max_bytes: usize) -> Self {
+        let mut interner = Self::new();
+        interner.max_bytes = Some(max_bytes);
+        interner
+    }
+
+    /// Returns the interned handle for `s`, copying `s` into the interner if it
+    /// is not present yet.
+    ///
+    /// Looking up a string that is already interned doesn't allocate.
+    pub fn intern(&mut self, s: &str) -> ByteStr {
+        if let Some(interned) = self.set.get(s) {
+            self.hits = self.hits.saturating_add(1);
+            return interned.clone();
+        }
+
+        self.misses = self.misses.saturating_add(1);
+        let interned =
+            unsafe { ByteStr::from_utf8_unchecked(Bytes::copy_from_slice(s.as_bytes())) };
+        if let Some(max_bytes) = self.max_bytes {
+            if self.bytes + s.len() > max_bytes {
+                self.evict_unused();
+            }
+            if self.bytes + s.len() > max_bytes {
+                return interned;
+            }
+        }
+        self.bytes += s.len();
+        self.set.insert(interned.clone());
+        interned
+    }
+
+    /// Seeds the interner with a static string, so that interning equal content
+    /// returns a handle to `s` itself and never allocates.
+    ///
+    /// If the content is already interned, the existing handle is returned.
+    /// Static strings don't count towards the memory limit and are never evicted.
+    pub fn intern_static(&mut self, s: &'static str) -> ByteStr {
+        if let Some(interned) = self.set.get(s) {
+            return interned.clone();
+        }
+        let interned = ByteStr::from_static(s);
+        self.set.insert(interned.clone());
+        interned
+    }
+
+    /// Returns the interned handle for `s`, if there is one, without interning it.
+    #[must_use]
+    pub fn get(&self, s: &str) -> Option<ByteStr> {
+        self.set.get(s).cloned()
+    }
+
+    /// Returns `true` if `s` is interned.
+    #[must_use]
+    pub fn contains(&self, s: &str) -> bool {
+        self.set.contains(s)
+    }
+
+    /// Returns the number of interned strings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Returns `true` if no strings are interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Returns statistics about the contents and use of the interner.
+    #[must_use]
+    pub fn stats(&self) -> InternerStats {
+        InternerStats {
+            entries: self.set.len(),
+            bytes: self.bytes,
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+
+    /// Removes the strings that are not used outside of the interner anymore,
+    /// returning how many were removed.
+    ///
+    /// Static strings are never removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStrInterner;
+    ///
+    /// let mut interner = ByteStrInterner::new();
+    /// let kept = interner.intern("kept");
+    /// interner.intern("dropped");
+    ///
+    /// assert_eq!(interner.evict_unused(), 1);
+    /// assert!(interner.contains("kept"));
+    /// assert_eq!(interner.stats().bytes, 4);
+    /// ```
+    pub fn evict_unused(&mut self) -> usize {
+        let before = self.set.len();
+        let mut freed = 0;
+        self.set.retain(|s| {
+            let unused = s.is_unique();
+            if unused {
+                freed += s.len();
+            }
+            !unused
+        });
+        self.bytes -= freed;
+        before - self.set.len()
+    }
+
+    /// Removes all strings from the interner. Handles that were handed out
+    /// remain valid.
+    pub fn clear(&mut self) {
+        self.set.clear();
+        self.bytes = 0;
+    }
+}
+
+/// A thread-safe [`ByteStrInterner`].
+///
+/// Lookups of strings that are already interned only take a shared lock, so
+/// they can proceed in parallel; interning a new string takes an exclusive lock.
+///
+/// This type requires the `std` feature, which provides the lock, or, in
+/// `no_std` builds, the `spin` feature, which replaces it with a spin lock.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, SyncByteStrInterner};
+///
+/// static INTERNER: SyncByteStrInterner = SyncByteStrInterner::new();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| INTERNER.intern("user-agent")))
+///     .collect();
+/// let interned: Vec<ByteStr> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+///
+/// assert!(interned.iter().all(|s| ByteStr::ptr_eq(s, &interned[0])));
+/// assert_eq!(INTERNER.stats().entries, 1);
+/// ```
+#[cfg(any(feature = "std", feature = "spin"))]
+#[derive(Debug, Default)]
+pub struct SyncByteStrInterner {
+    inner: RwLock<ByteStrInterner>,
+    // Hits served under the shared lock, which can't update `inner`.
+    shared_hits: AtomicUsize,
+}
+
+#[cfg(any(feature = "std", feature = "spin"))]
+impl SyncByteStrInterner {
+    /// Creates an empty interner without a memory limit.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_interner(ByteStrInterner::new())
+    }
+
+    /// Creates an empty interner that allocates at most `max_bytes` bytes for
+    /// its strings. See [`ByteStrInterner::with_max_bytes`].
+    #[must_use]
+    pub const fn with_max_bytes(max_bytes: usize) -> Self {
+        Self::from_interner(ByteStrInterner::with_max_bytes(max_bytes))
+    }
+
+    const fn from_interner(interner: ByteStrInterner) -> Self {
+        Self {
+            inner: RwLock::new(interner),
+            shared_hits: AtomicUsize::new(0),
+        }
+    }
+
+    // The interner is never left half-updated by a panic, so a poisoned lock
+    // is used as is.
+    #[cfg(feature = "std")]
+    fn read(&self) -> RwLockReadGuard<'_, ByteStrInterner> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(feature = "std")]
+    fn write(&self) -> RwLockWriteGuard<'_, ByteStrInterner> {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn read(&self) -> RwLockReadGuard<'_, ByteStrInterner> {
+        self.inner.read()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn write(&self) -> RwLockWriteGuard<'_, ByteStrInterner> {
+        self.inner.write()
+    }
+
+    /// Returns the interned handle for `s`, copying `s` into the interner if it
+    /// is not present yet. See [`ByteStrInterner::intern`].
+    pub fn intern(&self, s: &str) -> ByteStr {
+        let found = self.read().get(s);
+        if let Some(interned) = found {
+            self.shared_hits.fetch_add(1, Ordering::Relaxed);
+            return interned;
+        }
+        self.write().intern(s)
+    }
+
+    /// Seeds the interner with a static string. See [`ByteStrInterner::intern_static`].
+    pub fn intern_static(&self, s: &'static str) -> ByteStr {
+        self.write().intern_static(s)
+    }
+
+    /// Returns the interned handle for `s`, if there is one, without interning it.
+    #[must_use]
+    pub fn get(&self, s: &str) -> Option<ByteStr> {
+        self.read().get(s)
+    }
+
+    /// Returns `true` if `s` is interned.
+    #[must_use]
+    pub fn contains(&self, s: &str) -> bool {
+        self.read().contains(s)
+    }
+
+    /// Returns the number of interned strings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns `true` if no strings are interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Returns statistics about the contents and use of the interner.
+    #[must_use]
+    pub fn stats(&self) -> InternerStats {
+        let mut stats = self.read().stats();
+        stats.hits = stats
+            .hits
+            .saturating_add(self.shared_hits.load(Ordering::Relaxed));
+        stats
+    }
+
+    /// Removes the strings that are not used outside of the interner anymore,
+    /// returning how many were removed. See [`ByteStrInterner::evict_unused`].
+    pub fn evict_unused(&self) -> usize {
+        self.write().evict_unused()
+    }
+
+    /// Removes all strings from the interner. Handles that were handed out
+    /// remain valid.
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    /// Consumes the interner, returning the single-threaded interner inside.
+    #[must_use]
+    pub fn into_inner(self) -> ByteStrInterner {
+        let hits = self.shared_hits.into_inner();
+        #[cfg(feature = "std")]
+        let mut interner = self
+            .inner
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        let mut interner = self.inner.into_inner();
+        interner.hits = interner.hits.saturating_add(hits);
+        interner
+    }
+}
+
+#[cfg(any(feature = "std", feature = "spin"))]
+impl From<ByteStrInterner> for SyncByteStrInterner {
+    fn from(interner: ByteStrInterner) -> Self {
+        Self::from_interner(interner)
+    }
+}
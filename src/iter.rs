@@ -0,0 +1,776 @@
+//! Iterator types returned by the splitting and searching methods of [`ByteStr`]
+//! and [`ByteRope`].
+//!
+//! Every iterator yields zero-copy slices of the string it was created from,
+//! and mirrors the `str` iterator of the same name.
+
+use core::fmt;
+use core::iter::{FlatMap, FusedIterator};
+use core::{mem, str};
+
+use crate::pattern::{DoubleEndedPattern, Pattern, Searcher};
+use crate::{ByteRope, ByteStr};
+
+fn slice(source: &ByteStr, (start, end): (usize, usize)) -> ByteStr {
+    source.slice_ref(&source.as_str()[start..end])
+}
+
+/// The state machine shared by the `split` family, ported from `str`'s own
+/// implementation so that edge cases (empty patterns, trailing separators)
+/// behave identically. It yields byte ranges into the haystack.
+#[derive(Clone)]
+pub(crate) struct SplitInternal<P> {
+    start: usize,
+    end: usize,
+    searcher: Searcher<P>,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+
+impl<P: Pattern> SplitInternal<P> {
+    pub(crate) const fn new(pat: P, len: usize, allow_trailing_empty: bool) -> Self {
+        Self {
+            start: 0,
+            end: len,
+            searcher: Searcher::new(pat, len),
+            allow_trailing_empty,
+            finished: false,
+        }
+    }
+
+    const fn get_end(&mut self) -> Option<(usize, usize)> {
+        if !self.finished {
+            self.finished = true;
+            if self.allow_trailing_empty || self.end > self.start {
+                return Some((self.start, self.end));
+            }
+        }
+        None
+    }
+
+    fn next(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        match self.searcher.next_match(haystack) {
+            Some((a, b)) => {
+                let elt = (self.start, a);
+                self.start = b;
+                Some(elt)
+            }
+            None => self.get_end(),
+        }
+    }
+
+    fn next_inclusive(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        match self.searcher.next_match(haystack) {
+            Some((_, b)) => {
+                let elt = (self.start, b);
+                self.start = b;
+                Some(elt)
+            }
+            None => self.get_end(),
+        }
+    }
+
+    fn next_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back(haystack) {
+                Some((a, b)) if a != b => return Some((a, b)),
+                _ if self.finished => return None,
+                _ => {}
+            }
+        }
+
+        if let Some((a, b)) = self.searcher.next_match_back(haystack) {
+            let elt = (b, self.end);
+            self.end = a;
+            Some(elt)
+        } else {
+            self.finished = true;
+            Some((self.start, self.end))
+        }
+    }
+
+    fn next_back_inclusive(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back_inclusive(haystack) {
+                Some((a, b)) if a != b => return Some((a, b)),
+                _ if self.finished => return None,
+                _ => {}
+            }
+        }
+
+        if let Some((_, b)) = self.searcher.next_match_back(haystack) {
+            let elt = (b, self.end);
+            self.end = b;
+            Some(elt)
+        } else {
+            self.finished = true;
+            Some((self.start, self.end))
+        }
+    }
+}
+
+/// A `SplitInternal` limited to `count` items, the last one holding the remainder.
+#[derive(Clone)]
+pub(crate) struct SplitNInternal<P> {
+    iter: SplitInternal<P>,
+    count: usize,
+}
+
+impl<P: Pattern> SplitNInternal<P> {
+    pub(crate) const fn new(pat: P, len: usize, count: usize) -> Self {
+        Self {
+            iter: SplitInternal::new(pat, len, true),
+            count,
+        }
+    }
+
+    fn next(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.iter.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.iter.next(haystack)
+            }
+        }
+    }
+
+    fn next_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.iter.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.iter.next_back(haystack)
+            }
+        }
+    }
+}
+
+// Defines a public iterator wrapping `$inner`, advancing it with `$forward`
+// from the front and `$backward` from the back.
+macro_rules! split_iterator {
+    (
+        $(#[$attr:meta])*
+        $name:ident<P>($inner:ident) => $forward:ident, $backward:ident, $method:literal
+    ) => {
+        $(#[$attr])*
+        #[doc = concat!("This struct is created by [`ByteStr::", $method, "`].")]
+        #[derive(Clone)]
+        pub struct $name<P> {
+            source: ByteStr,
+            inner: $inner<P>,
+        }
+
+        impl<P> $name<P> {
+            pub(crate) const fn new(source: ByteStr, inner: $inner<P>) -> Self {
+                Self { source, inner }
+            }
+        }
+
+        impl<P: Pattern> Iterator for $name<P> {
+            type Item = ByteStr;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner
+                    .$forward(self.source.as_str())
+                    .map(|range| slice(&self.source, range))
+            }
+        }
+
+        impl<P: DoubleEndedPattern> DoubleEndedIterator for $name<P> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner
+                    .$backward(self.source.as_str())
+                    .map(|range| slice(&self.source, range))
+            }
+        }
+
+        impl<P: Pattern> FusedIterator for $name<P> {}
+
+        impl<P> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("source", &self.source)
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+split_iterator! {
+    /// An iterator over substrings of a `ByteStr`, separated by a pattern.
+    Split<P>(SplitInternal) => next, next_back, "split"
+}
+
+split_iterator! {
+    /// An iterator over substrings of a `ByteStr`, separated by a pattern and
+    /// yielded in reverse order.
+    RSplit<P>(SplitInternal) => next_back, next, "rsplit"
+}
+
+split_iterator! {
+    /// An iterator over substrings of a `ByteStr`, separated by a pattern, where a
+    /// trailing empty substring is skipped.
+    SplitTerminator<P>(SplitInternal) => next, next_back, "split_terminator"
+}
+
+split_iterator! {
+    /// An iterator over substrings of a `ByteStr`, separated by a pattern, where a
+    /// trailing empty substring is skipped, yielded in reverse order.
+    RSplitTerminator<P>(SplitInternal) => next_back, next, "rsplit_terminator"
+}
+
+split_iterator! {
+    /// An iterator over substrings of a `ByteStr`, each terminated by a match of
+    /// a pattern that is included in the substring.
+    SplitInclusive<P>(SplitInternal) => next_inclusive, next_back_inclusive, "split_inclusive"
+}
+
+/// An iterator over at most `n` substrings of a `ByteStr`, separated by a pattern.
+///
+/// This struct is created by [`ByteStr::splitn`].
+#[derive(Clone)]
+pub struct SplitN<P> {
+    source: ByteStr,
+    inner: SplitNInternal<P>,
+}
+
+impl<P> SplitN<P> {
+    pub(crate) const fn new(source: ByteStr, inner: SplitNInternal<P>) -> Self {
+        Self { source, inner }
+    }
+}
+
+impl<P: Pattern> Iterator for SplitN<P> {
+    type Item = ByteStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next(self.source.as_str())
+            .map(|range| slice(&self.source, range))
+    }
+}
+
+impl<P: Pattern> FusedIterator for SplitN<P> {}
+
+impl<P> fmt::Debug for SplitN<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitN")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over at most `n` substrings of a `ByteStr`, separated by a pattern
+/// and yielded in reverse order.
+///
+/// This struct is created by [`ByteStr::rsplitn`].
+#[derive(Clone)]
+pub struct RSplitN<P> {
+    source: ByteStr,
+    inner: SplitNInternal<P>,
+}
+
+impl<P> RSplitN<P> {
+    pub(crate) const fn new(source: ByteStr, inner: SplitNInternal<P>) -> Self {
+        Self { source, inner }
+    }
+}
+
+impl<P: Pattern> Iterator for RSplitN<P> {
+    type Item = ByteStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back(self.source.as_str())
+            .map(|range| slice(&self.source, range))
+    }
+}
+
+impl<P: Pattern> FusedIterator for RSplitN<P> {}
+
+impl<P> fmt::Debug for RSplitN<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RSplitN")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over the lines of a `ByteStr`.
+///
+/// Lines are split at line endings that are either newlines (`\n`) or sequences
+/// of a carriage return followed by a line feed (`\r\n`).
+///
+/// This struct is created by [`ByteStr::lines`].
+#[derive(Clone, Debug)]
+pub struct Lines {
+    inner: SplitInclusive<char>,
+}
+
+impl Lines {
+    pub(crate) const fn new(source: ByteStr) -> Self {
+        let len = source.len();
+        Self {
+            inner: SplitInclusive::new(source, SplitInternal::new('\n', len, false)),
+        }
+    }
+
+    fn strip_line_ending(line: &ByteStr) -> ByteStr {
+        let Some(stripped) = line.strip_suffix('\n') else {
+            return line.clone();
+        };
+        stripped.strip_suffix('\r').unwrap_or(stripped)
+    }
+}
+
+impl Iterator for Lines {
+    type Item = ByteStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|line| Self::strip_line_ending(&line))
+    }
+}
+
+impl DoubleEndedIterator for Lines {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|line| Self::strip_line_ending(&line))
+    }
+}
+
+impl FusedIterator for Lines {}
+
+/// An owning iterator over the `char`s of a `ByteStr`.
+///
+/// This struct is created by [`ByteStr::into_chars`].
+#[derive(Clone, Debug)]
+pub struct IntoChars {
+    inner: IntoCharIndices,
+}
+
+impl IntoChars {
+    pub(crate) const fn new(source: ByteStr) -> Self {
+        Self {
+            inner: IntoCharIndices::new(source),
+        }
+    }
+
+    /// Returns the part of the string that has not been iterated over yet, as
+    /// a zero-copy slice.
+    #[must_use]
+    pub fn as_byte_str(&self) -> ByteStr {
+        self.inner.as_byte_str()
+    }
+}
+
+impl Iterator for IntoChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, c)| c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IntoChars {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, c)| c)
+    }
+}
+
+impl FusedIterator for IntoChars {}
+
+/// An owning iterator over the `char`s of a `ByteStr` and their byte offsets.
+///
+/// This struct is created by [`ByteStr::into_char_indices`].
+#[derive(Clone, Debug)]
+pub struct IntoCharIndices {
+    source: ByteStr,
+    start: usize,
+    end: usize,
+}
+
+impl IntoCharIndices {
+    pub(crate) const fn new(source: ByteStr) -> Self {
+        let end = source.len();
+        Self {
+            source,
+            start: 0,
+            end,
+        }
+    }
+
+    fn remaining(&self) -> &str {
+        &self.source.as_str()[self.start..self.end]
+    }
+
+    /// Returns the part of the string that has not been iterated over yet, as
+    /// a zero-copy slice.
+    #[must_use]
+    pub fn as_byte_str(&self) -> ByteStr {
+        self.source.slice(self.start..self.end)
+    }
+}
+
+impl Iterator for IntoCharIndices {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.remaining().chars().next()?;
+        let index = self.start;
+        self.start += c.len_utf8();
+        Some((index, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining().chars().size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IntoCharIndices {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let c = self.remaining().chars().next_back()?;
+        self.end -= c.len_utf8();
+        Some((self.end, c))
+    }
+}
+
+impl FusedIterator for IntoCharIndices {}
+
+// Defines a public iterator over the non-empty pieces of a string split on
+// every char matching `$is_whitespace`.
+macro_rules! whitespace_iterator {
+    ($(#[$attr:meta])* $name:ident => $is_whitespace:expr, $method:literal) => {
+        $(#[$attr])*
+        #[doc = concat!("This struct is created by [`ByteStr::", $method, "`].")]
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            inner: Split<fn(char) -> bool>,
+        }
+
+        impl $name {
+            pub(crate) fn new(source: ByteStr) -> Self {
+                let len = source.len();
+                let is_whitespace: fn(char) -> bool = $is_whitespace;
+                Self {
+                    inner: Split::new(source, SplitInternal::new(is_whitespace, len, true)),
+                }
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = ByteStr;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.by_ref().find(|s| !s.is_empty())
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.by_ref().rfind(|s| !s.is_empty())
+            }
+        }
+
+        impl FusedIterator for $name {}
+    };
+}
+
+whitespace_iterator! {
+    /// An iterator over the non-whitespace substrings of a `ByteStr`, separated by
+    /// any amount of whitespace.
+    SplitWhitespace => char::is_whitespace, "split_whitespace"
+}
+
+whitespace_iterator! {
+    /// An iterator over the non-whitespace substrings of a `ByteStr`, separated by
+    /// any amount of ASCII whitespace.
+    SplitAsciiWhitespace => |c| c.is_ascii_whitespace(), "split_ascii_whitespace"
+}
+
+// Defines a public iterator over the matches of a pattern, advancing the
+// searcher with `$forward` from the front and `$backward` from the back.
+macro_rules! match_iterator {
+    (
+        $(#[$attr:meta])*
+        $name:ident<P> => $item:ty, $forward:ident, $backward:ident, $map:expr, $method:literal
+    ) => {
+        $(#[$attr])*
+        #[doc = concat!("This struct is created by [`ByteStr::", $method, "`].")]
+        #[derive(Clone)]
+        pub struct $name<P> {
+            source: ByteStr,
+            searcher: Searcher<P>,
+        }
+
+        impl<P: Pattern> $name<P> {
+            pub(crate) const fn new(source: ByteStr, pat: P) -> Self {
+                let len = source.len();
+                Self {
+                    source,
+                    searcher: Searcher::new(pat, len),
+                }
+            }
+        }
+
+        impl<P: Pattern> Iterator for $name<P> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let map: fn(&ByteStr, (usize, usize)) -> $item = $map;
+                self.searcher
+                    .$forward(self.source.as_str())
+                    .map(|range| map(&self.source, range))
+            }
+        }
+
+        impl<P: DoubleEndedPattern> DoubleEndedIterator for $name<P> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let map: fn(&ByteStr, (usize, usize)) -> $item = $map;
+                self.searcher
+                    .$backward(self.source.as_str())
+                    .map(|range| map(&self.source, range))
+            }
+        }
+
+        impl<P: Pattern> FusedIterator for $name<P> {}
+
+        impl<P> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("source", &self.source)
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+match_iterator! {
+    /// An iterator over the matches of a pattern within a `ByteStr`.
+    Matches<P> => ByteStr, next_match, next_match_back, slice, "matches"
+}
+
+match_iterator! {
+    /// An iterator over the matches of a pattern within a `ByteStr`, yielded in
+    /// reverse order.
+    RMatches<P> => ByteStr, next_match_back, next_match, slice, "rmatches"
+}
+
+match_iterator! {
+    /// An iterator over the matches of a pattern within a `ByteStr`, together
+    /// with the byte index at which each match starts.
+    MatchIndices<P> => (usize, ByteStr), next_match, next_match_back,
+    |source, range| (range.0, slice(source, range)), "match_indices"
+}
+
+match_iterator! {
+    /// An iterator over the matches of a pattern within a `ByteStr`, together
+    /// with the byte index at which each match starts, yielded in reverse order.
+    RMatchIndices<P> => (usize, ByteStr), next_match_back, next_match,
+    |source, range| (range.0, slice(source, range)), "rmatch_indices"
+}
+
+/// An iterator over the `char`s of a [`ByteRope`].
+///
+/// This struct is created by [`ByteRope::chars`].
+#[derive(Clone, Debug)]
+pub struct RopeChars<'a> {
+    inner:
+        FlatMap<core::slice::Iter<'a, ByteStr>, str::Chars<'a>, fn(&'a ByteStr) -> str::Chars<'a>>,
+}
+
+impl<'a> RopeChars<'a> {
+    pub(crate) fn new(segments: &'a [ByteStr]) -> Self {
+        Self {
+            inner: segments.iter().flat_map(|segment| segment.chars()),
+        }
+    }
+}
+
+impl Iterator for RopeChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for RopeChars<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl FusedIterator for RopeChars<'_> {}
+
+/// An iterator over the lines of a [`ByteRope`], yielded as ropes.
+///
+/// Lines are split at line endings that are either newlines (`\n`) or sequences
+/// of a carriage return followed by a line feed (`\r\n`).
+///
+/// This struct is created by [`ByteRope::lines`].
+#[derive(Clone, Debug)]
+pub struct RopeLines<'a> {
+    front: ByteStr,
+    segments: core::slice::Iter<'a, ByteStr>,
+}
+
+impl<'a> RopeLines<'a> {
+    pub(crate) fn new(segments: &'a [ByteStr]) -> Self {
+        Self {
+            front: ByteStr::new(),
+            segments: segments.iter(),
+        }
+    }
+}
+
+impl Iterator for RopeLines<'_> {
+    type Item = ByteRope;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = ByteRope::new();
+        loop {
+            if let Some(end) = self.front.find('\n') {
+                line.push(self.front.slice(..end));
+                self.front = self.front.slice(end + 1..);
+                line.strip_carriage_return();
+                return Some(line);
+            }
+            line.push(mem::take(&mut self.front));
+            match self.segments.next() {
+                Some(segment) => self.front = segment.clone(),
+                None => return (!line.is_empty()).then_some(line),
+            }
+        }
+    }
+}
+
+impl FusedIterator for RopeLines<'_> {}
+
+// Defines a public iterator mapping the items of a `unicode_segmentation`
+// iterator over `source` with `$map`, which turns segments into zero-copy slices.
+#[cfg(feature = "unicode-segmentation")]
+macro_rules! segment_iterator {
+    ($(#[$attr:meta])* $name:ident => $inner:ident, $item:ty, $map:expr, $method:literal) => {
+        $(#[$attr])*
+        #[doc = concat!("This struct is created by [`ByteStr::", $method, "`].")]
+        #[derive(Debug)]
+        pub struct $name<'a> {
+            source: &'a ByteStr,
+            inner: unicode_segmentation::$inner<'a>,
+        }
+
+        impl<'a> $name<'a> {
+            pub(crate) const fn new(
+                source: &'a ByteStr,
+                inner: unicode_segmentation::$inner<'a>,
+            ) -> Self {
+                Self { source, inner }
+            }
+        }
+
+        impl Iterator for $name<'_> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let map: fn(&ByteStr, _) -> $item = $map;
+                self.inner.next().map(|segment| map(self.source, segment))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+    };
+}
+
+// Implements `DoubleEndedIterator` for iterators defined by `segment_iterator!`
+// whose inner iterator supports it.
+#[cfg(feature = "unicode-segmentation")]
+macro_rules! double_ended_segment_iterator {
+    ($($name:ident => $item:ty, $map:expr;)*) => {$(
+        impl DoubleEndedIterator for $name<'_> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let map: fn(&ByteStr, _) -> $item = $map;
+                self.inner.next_back().map(|segment| map(self.source, segment))
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "unicode-segmentation")]
+segment_iterator! {
+    /// An iterator over the extended grapheme clusters of a `ByteStr`.
+    Graphemes => Graphemes, ByteStr, ByteStr::slice_ref, "graphemes"
+}
+
+#[cfg(feature = "unicode-segmentation")]
+segment_iterator! {
+    /// An iterator over the extended grapheme clusters of a `ByteStr`, together
+    /// with the byte index at which each cluster starts.
+    GraphemeIndices => GraphemeIndices, (usize, ByteStr),
+    |source, (index, grapheme)| (index, source.slice_ref(grapheme)), "grapheme_indices"
+}
+
+#[cfg(feature = "unicode-segmentation")]
+segment_iterator! {
+    /// An iterator over the words of a `ByteStr`, leaving out whitespace and
+    /// punctuation.
+    UnicodeWords => UnicodeWords, ByteStr, ByteStr::slice_ref, "unicode_words"
+}
+
+#[cfg(feature = "unicode-segmentation")]
+segment_iterator! {
+    /// An iterator over the substrings of a `ByteStr` separated by word
+    /// boundaries, including whitespace and punctuation.
+    SplitWordBounds => UWordBounds, ByteStr, ByteStr::slice_ref, "split_word_bounds"
+}
+
+#[cfg(feature = "unicode-segmentation")]
+segment_iterator! {
+    /// An iterator over the sentences of a `ByteStr`.
+    Sentences => UnicodeSentences, ByteStr, ByteStr::slice_ref, "sentences"
+}
+
+#[cfg(feature = "unicode-segmentation")]
+double_ended_segment_iterator! {
+    Graphemes => ByteStr, ByteStr::slice_ref;
+    GraphemeIndices => (usize, ByteStr),
+        |source, (index, grapheme)| (index, source.slice_ref(grapheme));
+    UnicodeWords => ByteStr, ByteStr::slice_ref;
+    SplitWordBounds => ByteStr, ByteStr::slice_ref;
+}
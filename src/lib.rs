@@ -32,7 +32,7 @@
 //! // Create from static string (zero-cost)
 //! let static_str = ByteStr::from_static("Hello, world!");
 //!
-//! // Create from String (reuses allocation)
+//! // Create from String (reuses the allocation; short strings are stored inline)
 //! let from_string = ByteStr::from("Hello, world!".to_string());
 //!
 //! // Create from bytes with validation
@@ -110,6 +110,56 @@
 //! assert_eq!(identifier.as_str(), "let");
 //! ```
 //!
+//! For anything longer, [`ByteStrCursor`] consumes the input token by token and
+//! keeps track of the line and column for error messages:
+//!
+//! ```rust
+//! use bytestr::{ByteStr, ByteStrCursor};
+//!
+//! let mut cursor = ByteStrCursor::new(ByteStr::from("port=8080\nhost localhost\n"));
+//! let key = cursor.take_until('=');
+//! cursor.expect('=').unwrap();
+//! let value = cursor.take_until('\n');
+//! cursor.expect('\n').unwrap();
+//! assert_eq!((key.as_str(), value.as_str()), ("port", "8080"));
+//!
+//! cursor.take_until([' ', '=']);
+//! let err = cursor.expect('=').unwrap_err();
+//! assert_eq!(err.to_string(), "unexpected character ' ' at 2:5");
+//! ```
+//!
+//! When positions are needed after the fact, such as for diagnostics sent to an
+//! editor, [`LineIndex`] maps byte offsets to lines and columns and back,
+//! counting columns in bytes, `char`s or UTF-16 code units:
+//!
+//! ```rust
+//! use bytestr::{ByteStr, LineCol, LineIndex};
+//!
+//! let index = LineIndex::new(ByteStr::from("name = \"José\"\nage = 42\n"));
+//! let position = index.line_col(index.source().find("42").unwrap()).unwrap();
+//! assert_eq!(position, LineCol { line: 1, col: 6 });
+//! assert_eq!(index.bytes_to_utf16(LineCol { line: 0, col: 14 }), Some(LineCol { line: 0, col: 13 }));
+//! ```
+//!
+//! A slice returned by any zero-copy method can also be located in the string
+//! it came from with [`ByteStr::offset_in`], or paired with its range in a
+//! [`Spanned`].
+//!
+//! ### Building Strings
+//!
+//! [`ByteStrMut`] is a growable, UTF-8 guaranteed buffer that freezes into a
+//! `ByteStr` without copying:
+//!
+//! ```rust
+//! use bytestr::ByteStrMut;
+//! use core::fmt::Write;
+//!
+//! let mut buf = ByteStrMut::new();
+//! write!(buf, "{} {}", "GET", "/index.html").unwrap();
+//! let line = buf.freeze();
+//! assert_eq!(line, "GET /index.html");
+//! ```
+//!
 //! ## Optional Features
 //!
 //! ### Serde Support
@@ -120,24 +170,179 @@
 //! [dependencies]
 //! bytestr = { version = "0.2", features = ["serde"] }
 //! ```
+//!
+//! ### Standard Library Support
+//!
+//! Enable the `std` feature for vectored reads of a [`ByteStrBuf`] through
+//! [`Buf::chunks_vectored`](bytes::Buf::chunks_vectored), and for the
+//! thread-safe `SyncByteStrInterner`:
+//!
+//! ```toml
+//! [dependencies]
+//! bytestr = { version = "0.2", features = ["std"] }
+//! ```
+//!
+//! In `no_std` builds, enable the `spin` feature instead to get a
+//! `SyncByteStrInterner` that locks with a spin lock.
+//!
+//! ### Unicode Normalization
+//!
+//! Enable the `unicode-normalization` feature for `ByteStr::nfc` and the
+//! other normalization forms, and for the `Normalized` wrapper that compares
+//! strings regardless of their normalization:
+//!
+//! ```toml
+//! [dependencies]
+//! bytestr = { version = "0.2", features = ["unicode-normalization"] }
+//! ```
+//!
+//! ### Unicode Segmentation
+//!
+//! Enable the `unicode-segmentation` feature to split a `ByteStr` into
+//! grapheme clusters, words and sentences, as zero-copy slices, and to
+//! truncate it without splitting a grapheme cluster:
+//!
+//! ```toml
+//! [dependencies]
+//! bytestr = { version = "0.2", features = ["unicode-segmentation"] }
+//! ```
+//!
+//! ### Display Width
+//!
+//! Enable the `unicode-width` feature to measure, truncate and pad a `ByteStr`
+//! by terminal columns, for tables and log output with East Asian text or emoji:
+//!
+//! ```toml
+//! [dependencies]
+//! bytestr = { version = "0.2", features = ["unicode-width"] }
+//! ```
+//!
+//! ### Parser Combinators
+//!
+//! Enable the `nom` or `winnow` feature to use a `ByteStr` as parser input, so
+//! that parsers such as `tag`, `take_while1` and `alpha1` return `ByteStr`
+//! tokens sliced from it, which [`ByteStr::offset_in`] locates in the input.
+//! Parsing a `Located` input instead gives every token its byte offset in the
+//! source:
+//!
+//! ```toml
+//! [dependencies]
+//! bytestr = { version = "0.2", features = ["nom"] }
+//! ```
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod buf;
+mod bytestr_mut;
+mod caseless;
+mod cursor;
+mod decoder;
+mod error;
+mod format;
 mod helper;
 mod impls;
+mod interner;
+pub mod iter;
+mod line_index;
+#[cfg(any(feature = "nom", feature = "winnow"))]
+mod located;
+#[cfg(feature = "nom")]
+mod nom;
+#[cfg(feature = "unicode-normalization")]
+mod normalization;
+mod pattern;
+mod rope;
+#[cfg(feature = "unicode-segmentation")]
+mod segmentation;
 #[cfg(feature = "serde")]
 mod serde;
+mod span;
+mod transform;
+#[cfg(feature = "unicode-width")]
+mod width;
+#[cfg(feature = "winnow")]
+mod winnow;
 use alloc::borrow::Cow;
 use alloc::string::{FromUtf16Error, String};
+pub use buf::ByteStrBuf;
 use bytes::Bytes;
-use core::ops::Deref;
-use core::str::Utf8Error;
+pub use bytestr_mut::ByteStrMut;
+pub use caseless::{CaseInsensitive, CaseInsensitiveStr};
+use core::hash::Hasher;
+use core::ops::{Bound, Deref, RangeBounds};
+pub use cursor::{ByteStrCursor, Checkpoint, Location};
+pub use decoder::{Decoded, Utf8DecodeError, Utf8Decoder};
+pub use error::{ExpectError, FromUtf8Error, SliceError};
+#[cfg(any(feature = "std", feature = "spin"))]
+pub use interner::SyncByteStrInterner;
+pub use interner::{ByteStrInterner, InternerStats};
+pub use line_index::{LineCol, LineIndex};
+#[cfg(any(feature = "nom", feature = "winnow"))]
+pub use located::Located;
+#[cfg(feature = "unicode-normalization")]
+pub use normalization::Normalized;
+pub use pattern::{DoubleEndedPattern, Pattern};
+pub use rope::ByteRope;
+pub use span::Spanned;
+#[cfg(feature = "unicode-width")]
+pub use width::Aligned;
 
 /// A cheaply cloneable and sliceable immutable UTF-8 encoded string.
-#[derive(Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ByteStr(Bytes);
+///
+/// `Hash`, `Eq` and `Ord` behave exactly like they do for [`str`], so a `ByteStr`
+/// can be used as a map key and looked up with a plain `&str`.
+///
+/// Strings of up to [`ByteStr::INLINE_CAPACITY`] bytes converted from a `&str`
+/// or `String` are stored inline, so creating, cloning and dropping them never
+/// touches the heap or an atomic reference count. Slicing or cloning an inline
+/// string copies it, so unlike slices of a heap or static buffer, these copies
+/// do not point into the string they came from. They carry that string along
+/// with their offset in it instead, so that [`ByteStr::offset_in`] can still
+/// locate them.
+#[derive(Clone)]
+pub struct ByteStr(Repr);
+
+// Inline strings take the space of the buffer handle and its origin, so they
+// don't make a `ByteStr` any larger.
+const _: () = assert!(size_of::<ByteStr>() == 40);
+
+// The `offset` of a view is where it starts in the buffer it was created from,
+// or `UNKNOWN_OFFSET`. Together with `backing`, it tells whether two views are
+// of the same buffer, which is needed to rejoin them. Both are `u32`s, so that
+// the inline variant fits next to them, and are not tracked for buffers of
+// 4 GiB or more.
+#[derive(Clone)]
+enum Repr {
+    /// A view of a reference-counted buffer of (at least) `backing` bytes, or
+    /// of a `&'static str` if `backing` is `STATIC`, which owns no allocation.
+    Buffer {
+        bytes: Bytes,
+        backing: u32,
+        offset: u32,
+    },
+    /// A short string stored in place of the buffer handle. `buf` holds the
+    /// whole inline string this one was sliced from, zero-padded, and the
+    /// string is `len` bytes of it from `start`.
+    Inline {
+        start: u8,
+        len: u8,
+        buf: [u8; ByteStr::INLINE_CAPACITY],
+    },
+}
+
+const UNKNOWN_OFFSET: u32 = u32::MAX;
+/// The `backing` of a buffer whose size is not known.
+const UNKNOWN_BACKING: u32 = 0;
+/// The `backing` of a static string.
+const STATIC: u32 = u32::MAX;
 
 impl ByteStr {
+    /// The maximum length in bytes of strings stored inline, without a heap
+    /// allocation. It uses the space that the buffer handle would otherwise take.
+    pub const INLINE_CAPACITY: usize = 30;
+
     /// Creates an empty new `ByteStr`.
     ///
     /// This operation is very cheap as it doesn't allocate any memory.
@@ -153,7 +358,7 @@ impl ByteStr {
     /// ```
     #[must_use]
     pub const fn new() -> Self {
-        Self(Bytes::new())
+        Self::from_static("")
     }
 
     /// Converts a vector of bytes to a `ByteStr`.
@@ -178,13 +383,14 @@ impl ByteStr {
     ///
     /// # Errors
     ///
-    /// Returns an error if the provided bytes are not valid UTF-8.
-    pub fn from_utf8(bytes: impl Into<Bytes>) -> Result<Self, Utf8Error> {
+    /// Returns an error if the provided bytes are not valid UTF-8. The error
+    /// gives the bytes back, see [`FromUtf8Error::into_bytes`].
+    pub fn from_utf8(bytes: impl Into<Bytes>) -> Result<Self, FromUtf8Error> {
         let bytes = bytes.into();
 
         match core::str::from_utf8(bytes.as_ref()) {
             Ok(_) => Ok(unsafe { Self::from_utf8_unchecked(bytes) }),
-            Err(e) => Err(e),
+            Err(e) => Err(FromUtf8Error::new(bytes, e)),
         }
     }
 
@@ -267,7 +473,41 @@ impl ByteStr {
     /// ```
     #[must_use]
     pub const fn from_static(s: &'static str) -> Self {
-        unsafe { Self::from_utf8_unchecked(Bytes::from_static(s.as_bytes())) }
+        // Empty strings and strings of 4 GiB or more are not tracked, see `Repr`.
+        let offset = if s.is_empty() || s.len() >= UNKNOWN_OFFSET as usize {
+            UNKNOWN_OFFSET
+        } else {
+            0
+        };
+        Self(Repr::Buffer {
+            bytes: Bytes::from_static(s.as_bytes()),
+            backing: STATIC,
+            offset,
+        })
+    }
+
+    /// Creates a `ByteStr` by copying a string slice.
+    ///
+    /// Strings of up to [`ByteStr::INLINE_CAPACITY`] bytes are stored inline,
+    /// and longer ones are copied into a right-sized buffer. Unlike
+    /// `ByteStr::from(&str)`, which goes through a `String`, this never allocates
+    /// for short strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let name = String::from("content-type");
+    /// let s = ByteStr::copy_from_str(&name);
+    /// assert_eq!(s, "content-type");
+    /// assert_eq!(s.capacity(), 12);
+    /// ```
+    #[must_use]
+    pub fn copy_from_str(s: &str) -> Self {
+        Self::inline(s).unwrap_or_else(|| unsafe {
+            Self::from_utf8_unchecked(Bytes::copy_from_slice(s.as_bytes()))
+        })
     }
 
     /// Creates a `ByteStr` from bytes without UTF-8 validation.
@@ -290,12 +530,69 @@ impl ByteStr {
     /// memory unsafety issues with future users of the `ByteStr`.
     #[must_use]
     pub const unsafe fn from_utf8_unchecked(bytes: Bytes) -> Self {
-        Self(bytes)
+        unsafe { Self::from_buffer_unchecked(bytes.len(), bytes) }
     }
+
+    /// Creates a `ByteStr` viewing a buffer known to be `capacity` bytes large.
+    pub(crate) const unsafe fn from_buffer_unchecked(capacity: usize, bytes: Bytes) -> Self {
+        // A buffer of 4 GiB or more is not tracked, see `Repr`.
+        #[allow(clippy::cast_possible_truncation)]
+        let (backing, offset) = if capacity < STATIC as usize {
+            (capacity as u32, 0)
+        } else {
+            (UNKNOWN_BACKING, UNKNOWN_OFFSET)
+        };
+        Self(Repr::Buffer {
+            bytes,
+            backing,
+            offset,
+        })
+    }
+
+    /// Stores `s` inline if it is short enough.
+    pub(crate) fn inline(s: &str) -> Option<Self> {
+        let mut buf = [0; Self::INLINE_CAPACITY];
+        buf.get_mut(..s.len())?.copy_from_slice(s.as_bytes());
+        let len = u8::try_from(s.len()).ok()?;
+        Some(Self(Repr::Inline { start: 0, len, buf }))
+    }
+
+    /// Returns the given byte range of `self`, which the caller has checked to be
+    /// in bounds and on char boundaries, keeping track of where it came from.
+    fn slice_unchecked(&self, start: usize, end: usize) -> Self {
+        match &self.0 {
+            Repr::Buffer {
+                bytes,
+                backing,
+                offset,
+            } => Self(Repr::Buffer {
+                bytes: bytes.slice(start..end),
+                backing: *backing,
+                offset: u32::try_from(start)
+                    .ok()
+                    .and_then(|start| offset.checked_add(start))
+                    .filter(|&offset| offset != UNKNOWN_OFFSET)
+                    .unwrap_or(UNKNOWN_OFFSET),
+            }),
+            // `end` is within the inline string, so both bounds fit in a `u8`.
+            #[allow(clippy::cast_possible_truncation)]
+            Repr::Inline {
+                start: root_start,
+                buf,
+                ..
+            } => Self(Repr::Inline {
+                start: root_start + start as u8,
+                len: (end - start) as u8,
+                buf: *buf,
+            }),
+        }
+    }
+
     /// Unwraps the `ByteStr` into the inner `Bytes` object.
     ///
     /// This operation consumes the `ByteStr` and returns the underlying
-    /// `Bytes` without any copying.
+    /// `Bytes` without any copying, except for inline strings, which are copied
+    /// into a new buffer.
     ///
     /// # Examples
     ///
@@ -306,8 +603,117 @@ impl ByteStr {
     /// let bytes = s.into_bytes();
     /// assert_eq!(bytes.as_ref(), b"Hello, world!");
     /// ```
+    #[must_use]
     pub fn into_bytes(self) -> Bytes {
-        self.0
+        match self.0 {
+            Repr::Buffer { bytes, .. } => bytes,
+            Repr::Inline { .. } => Bytes::copy_from_slice(self.as_bytes()),
+        }
+    }
+
+    /// Converts the `ByteStr` into a `String`.
+    ///
+    /// The allocation is reused when this `ByteStr` is the only handle to its
+    /// buffer; the contents are only copied when the buffer is shared with other
+    /// `ByteStr`s or `Bytes`, or is a static or inline string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from(String::from("Hello, world!"));
+    /// let owned: String = s.into_string();
+    /// assert_eq!(owned, "Hello, world!");
+    /// ```
+    #[must_use]
+    pub fn into_string(self) -> String {
+        match self.0 {
+            Repr::Buffer { bytes, backing, .. } if backing != STATIC => unsafe {
+                String::from_utf8_unchecked(bytes.into())
+            },
+            _ => String::from(self.as_str()),
+        }
+    }
+
+    /// Converts the `ByteStr` into a mutable [`ByteStrMut`] without copying, if
+    /// this `ByteStr` is the only handle to its buffer.
+    ///
+    /// Inline strings have no buffer to reuse; they are copied into a new one,
+    /// which is cheap given their size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged if the buffer is shared with other `ByteStr`s or
+    /// `Bytes`, or if it is a static string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Hello, world! ".repeat(10));
+    /// let shared = s.clone();
+    /// let s = s.try_into_mut().unwrap_err();
+    ///
+    /// drop(shared);
+    /// let mut buf = s.try_into_mut().unwrap();
+    /// buf.push_str("Bye!");
+    /// assert!(buf.ends_with("world! Bye!"));
+    ///
+    /// assert!(ByteStr::from_static("static").try_into_mut().is_err());
+    /// ```
+    pub fn try_into_mut(self) -> Result<ByteStrMut, Self> {
+        match self.0 {
+            Repr::Buffer {
+                backing: STATIC, ..
+            } => Err(self),
+            Repr::Inline { .. } => Ok(ByteStrMut::from(self.as_str())),
+            Repr::Buffer {
+                bytes,
+                backing,
+                offset,
+            } => match bytes.try_into_mut() {
+                Ok(bytes) => Ok(unsafe { ByteStrMut::from_utf8_unchecked(bytes) }),
+                Err(bytes) => Err(Self(Repr::Buffer {
+                    bytes,
+                    backing,
+                    offset,
+                })),
+            },
+        }
+    }
+
+    /// Gives mutable access to the contents through a [`ByteStrMut`], copying
+    /// them first only if the buffer is shared or static (copy-on-write).
+    ///
+    /// When this `ByteStr` is the only handle to its buffer, the allocation is
+    /// modified in place. Otherwise the contents are copied into a new buffer,
+    /// leaving other handles untouched. Either way, `self` holds the modified
+    /// string once `f` returns.
+    ///
+    /// If `f` panics, `self` is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let mut s = ByteStr::from("Hello");
+    /// let shared = s.clone();
+    /// s.make_mut(|buf| buf.push_str(", world!"));
+    ///
+    /// assert_eq!(s, "Hello, world!");
+    /// assert_eq!(shared, "Hello");
+    /// ```
+    pub fn make_mut<R>(&mut self, f: impl FnOnce(&mut ByteStrMut) -> R) -> R {
+        let mut buf = match core::mem::take(self).try_into_mut() {
+            Ok(buf) => buf,
+            Err(shared) => ByteStrMut::from(shared.as_str()),
+        };
+        let result = f(&mut buf);
+        *self = buf.freeze();
+        result
     }
 
     /// Extracts a string slice containing the entire string.
@@ -320,6 +726,7 @@ impl ByteStr {
     /// let s = ByteStr::from("Hello, world!");
     /// assert_eq!(s.as_str(), "Hello, world!");
     /// ```
+    #[must_use]
     pub fn as_str(&self) -> &str {
         unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
     }
@@ -332,12 +739,113 @@ impl ByteStr {
     pub fn truncate(&mut self, len: usize) {
         if len < self.len() {
             assert!(self.deref().is_char_boundary(len));
-            unsafe {
-                self.as_bytes_mut().truncate(len);
+            match &mut self.0 {
+                Repr::Buffer { bytes, .. } => bytes.truncate(len),
+                // `len` is below the current inline length, so it fits in a `u8`.
+                #[allow(clippy::cast_possible_truncation)]
+                Repr::Inline {
+                    len: inline_len, ..
+                } => *inline_len = len as u8,
             }
         }
     }
 
+    /// Shortens the string, keeping the first `len` bytes and dropping the rest.
+    ///
+    /// This is the non-panicking version of [`ByteStr::truncate`]. Like it, this
+    /// does nothing if `len` is greater than or equal to the string's length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::NotCharBoundary`] and leaves the string untouched
+    /// if `len` falls inside a multi-byte UTF-8 sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, SliceError};
+    ///
+    /// let mut s = ByteStr::from("héllo");
+    /// assert_eq!(s.try_truncate(2), Err(SliceError::NotCharBoundary { index: 2 }));
+    /// assert_eq!(s, "héllo");
+    /// s.try_truncate(3).unwrap();
+    /// assert_eq!(s, "hé");
+    /// ```
+    pub fn try_truncate(&mut self, len: usize) -> Result<(), SliceError> {
+        if !self.is_char_boundary(len) && len < self.len() {
+            return Err(SliceError::NotCharBoundary { index: len });
+        }
+        self.truncate(len);
+        Ok(())
+    }
+
+    /// Shortens the string to at most `len` bytes, rounding down to the nearest
+    /// UTF-8 code point boundary so that no character is split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let mut s = ByteStr::from("❤️🧡");
+    /// s.truncate_floor(8);
+    /// assert_eq!(s, "❤️");
+    /// ```
+    pub fn truncate_floor(&mut self, len: usize) {
+        self.truncate(self.floor_char_boundary(len));
+    }
+
+    /// Finds the closest code point boundary at or before `index`.
+    ///
+    /// Returns the string's length if `index` is past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("❤️🧡💛");
+    /// assert_eq!(s.len(), 14);
+    /// assert_eq!(s.floor_char_boundary(13), 10);
+    /// assert_eq!(s.floor_char_boundary(100), 14);
+    /// assert_eq!(s.take(s.floor_char_boundary(13)), "❤️🧡");
+    /// ```
+    #[must_use]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            return self.len();
+        }
+        // A code point is at most four bytes long, so this loop runs at most four times.
+        (0..=index)
+            .rev()
+            .find(|&i| self.is_char_boundary(i))
+            .unwrap_or(0)
+    }
+
+    /// Finds the closest code point boundary at or after `index`.
+    ///
+    /// Returns the string's length if `index` is past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("❤️🧡💛");
+    /// assert_eq!(s.ceil_char_boundary(13), 14);
+    /// assert_eq!(s.ceil_char_boundary(7), 10);
+    /// assert_eq!(s.skip(s.ceil_char_boundary(7)), "💛");
+    /// ```
+    #[must_use]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            return self.len();
+        }
+        (index..self.len())
+            .find(|&i| self.is_char_boundary(i))
+            .unwrap_or(self.len())
+    }
+
     /// Returns a slice of self that is equivalent to the given subset.
     ///
     /// This operation creates a new `ByteStr` that references a subset of the
@@ -356,12 +864,96 @@ impl ByteStr {
     /// assert_eq!(sliced.as_str(), "world");
     /// ```
     ///
+    /// An inline string is copied when it is cloned or sliced, so `subset` must
+    /// be borrowed from `self` itself, not from one of its clones or slices.
+    ///
     /// # Panics
     ///
     /// Panics if the given `subset` is not contained within the `ByteStr`.
     #[must_use]
     pub fn slice_ref(&self, subset: &str) -> Self {
-        unsafe { Self::from_utf8_unchecked(self.0.slice_ref(subset.as_bytes())) }
+        let start = (subset.as_ptr() as usize).wrapping_sub(self.as_str().as_ptr() as usize);
+        if start <= self.len() && subset.len() <= self.len() - start {
+            return self.slice_unchecked(start, start + subset.len());
+        }
+        // Like `Bytes::slice_ref`, an empty subset is accepted from anywhere.
+        assert!(subset.is_empty(), "subset is out of bounds of the ByteStr");
+        Self::new()
+    }
+
+    /// Returns a zero-copy slice of self for the provided byte range.
+    ///
+    /// This is the `ByteStr` counterpart of indexing with `&s[range]`: the
+    /// returned `ByteStr` shares the buffer of `self` instead of borrowing from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Hello, world!");
+    /// assert_eq!(s.slice(7..12), "world");
+    /// assert_eq!(s.slice(..=4), "Hello");
+    /// assert_eq!(s.slice(7..), "world!");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if either end is not on a UTF-8
+    /// code point boundary. See [`ByteStr::try_slice`] for a non-panicking version.
+    #[must_use]
+    #[track_caller]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        match self.try_slice(range) {
+            Ok(slice) => slice,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Returns a zero-copy slice of self for the provided byte range, or `None`
+    /// if the range is out of bounds or not on UTF-8 code point boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Hello, 世界!");
+    /// assert_eq!(s.get(7..10).unwrap(), "世");
+    /// assert!(s.get(7..8).is_none());
+    /// assert!(s.get(..100).is_none());
+    /// ```
+    #[must_use]
+    pub fn get(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        self.try_slice(range).ok()
+    }
+
+    /// Returns a zero-copy slice of self for the provided byte range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, SliceError};
+    ///
+    /// let s = ByteStr::from("Hello, 世界!");
+    /// assert_eq!(s.try_slice(..5).unwrap(), "Hello");
+    /// assert_eq!(s.try_slice(8..), Err(SliceError::NotCharBoundary { index: 8 }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::OutOfBounds`] if the range does not lie within the
+    /// string, and [`SliceError::NotCharBoundary`] if either end falls inside a
+    /// multi-byte UTF-8 sequence.
+    pub fn try_slice(&self, range: impl RangeBounds<usize>) -> Result<Self, SliceError> {
+        let (start, end) = resolve_range(&range, self.len())?;
+        for index in [start, end] {
+            if !self.as_str().is_char_boundary(index) {
+                return Err(SliceError::NotCharBoundary { index });
+            }
+        }
+
+        Ok(self.slice_unchecked(start, end))
     }
 
     /// Removes all contents of the `ByteStr` while retaining the capacity.
@@ -377,10 +969,12 @@ impl ByteStr {
     /// assert!(s.is_empty());
     /// ```
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.truncate(0);
     }
 
-    /// Provides a reference to the inner `Bytes` object.
+    /// Returns the contents of the string as a byte slice.
+    ///
+    /// Use [`ByteStr::into_bytes`] to get a `Bytes` handle to the data instead.
     ///
     /// # Examples
     ///
@@ -390,13 +984,20 @@ impl ByteStr {
     /// let s = ByteStr::from("Hello, world!");
     /// let bytes = s.as_bytes();
     /// assert_eq!(bytes.len(), 13);
+    /// assert_eq!(&bytes[..5], b"Hello");
     /// ```
-    pub const fn as_bytes(&self) -> &Bytes {
-        &self.0
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.0 {
+            Repr::Buffer { bytes, .. } => bytes,
+            Repr::Inline { start, len, buf } => &buf[*start as usize..][..*len as usize],
+        }
     }
 
     /// Provides a mutable reference to the inner `Bytes` object.
     ///
+    /// Inline strings are first copied into a `Bytes` buffer, which allocates.
+    ///
     /// # Examples
     ///
     /// ```
@@ -413,8 +1014,28 @@ impl ByteStr {
     ///
     /// The caller must ensure that the content of the slice is valid UTF-8
     /// before the borrow ends and the `ByteStr` is used.
-    pub const unsafe fn as_bytes_mut(&mut self) -> &mut Bytes {
-        &mut self.0
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut Bytes {
+        if let Repr::Inline { .. } = self.0 {
+            self.0 = Repr::Buffer {
+                bytes: Bytes::copy_from_slice(self.as_bytes()),
+                backing: UNKNOWN_BACKING,
+                offset: UNKNOWN_OFFSET,
+            };
+        }
+        match &mut self.0 {
+            // The caller may swap in any buffer, so it can no longer be assumed
+            // static, nor to be at a known place in a buffer of known size.
+            Repr::Buffer {
+                bytes,
+                backing,
+                offset,
+            } => {
+                *backing = UNKNOWN_BACKING;
+                *offset = UNKNOWN_OFFSET;
+                bytes
+            }
+            Repr::Inline { .. } => unreachable!(),
+        }
     }
 
     /// Returns `true` if the `ByteStr` has a length of zero bytes.
@@ -432,7 +1053,7 @@ impl ByteStr {
     /// ```
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.len() == 0
     }
 
     /// Returns the length of this `ByteStr` in bytes.
@@ -447,28 +1068,395 @@ impl ByteStr {
     /// ```
     #[must_use]
     pub const fn len(&self) -> usize {
-        self.0.len()
+        match &self.0 {
+            Repr::Buffer { bytes, .. } => bytes.len(),
+            Repr::Inline { len, .. } => *len as usize,
+        }
     }
 
-    /// Returns the capacity of this `ByteStr` in bytes.
+    /// Returns the size in bytes of the buffer this `ByteStr` keeps alive.
     ///
-    /// The capacity represents the total amount of memory allocated
-    /// for this `ByteStr`, which may be larger than the length.
+    /// Slicing a `ByteStr` shares its buffer, so a short slice may pin a much
+    /// larger allocation; this reports the size of that allocation, as far as it
+    /// is known. It is exact for strings created from a `String` or by freezing a
+    /// [`ByteStrMut`], and a lower bound for those created from arbitrary `Bytes`,
+    /// which may themselves be slices of a larger buffer. Static and inline
+    /// strings own no allocation, so their capacity is their length.
     ///
     /// # Examples
     ///
     /// ```
     /// use bytestr::ByteStr;
     ///
-    /// let s = ByteStr::from("Hello!");
-    /// assert!(s.capacity() >= s.len());
+    /// let request = ByteStr::from("Host: example.com\r\n".repeat(100));
+    /// let host = request.slice(6..17);
+    /// assert_eq!(host, "example.com");
+    /// assert_eq!(host.len(), 11);
+    /// assert_eq!(host.capacity(), 1900);
     /// ```
     #[must_use]
     pub const fn capacity(&self) -> usize {
-        // Bytes doesn't expose capacity directly, but we can use len() as a reasonable approximation
-        // since Bytes manages memory efficiently
-        self.0.len()
+        match &self.0 {
+            Repr::Buffer { backing, .. }
+                if *backing != STATIC && *backing as usize > self.len() =>
+            {
+                *backing as usize
+            }
+            _ => self.len(),
+        }
+    }
+
+    /// Returns `true` if this `ByteStr` was created from a `&'static str`.
+    ///
+    /// Static strings (including slices of them) own no allocation, so they never
+    /// keep memory alive and never need to be compacted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// assert!(ByteStr::from_static("GET").is_static());
+    /// assert!(ByteStr::from_static("GET /").slice(..3).is_static());
+    /// assert!(!ByteStr::from(String::from("GET")).is_static());
+    /// ```
+    #[must_use]
+    pub const fn is_static(&self) -> bool {
+        matches!(
+            self.0,
+            Repr::Buffer {
+                backing: STATIC,
+                ..
+            }
+        )
+    }
+
+    /// Returns `true` if this `ByteStr` is the only handle to its buffer.
+    ///
+    /// This is `false` for static strings, and for buffers shared with clones,
+    /// slices or `Bytes` handles. Inline strings are always unique, since
+    /// cloning copies them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("hello ".repeat(10));
+    /// assert!(s.is_unique());
+    /// let clone = s.clone();
+    /// assert!(!s.is_unique());
+    /// drop(clone);
+    /// assert!(s.is_unique());
+    /// ```
+    #[must_use]
+    pub fn is_unique(&self) -> bool {
+        match &self.0 {
+            Repr::Buffer {
+                backing: STATIC, ..
+            } => false,
+            Repr::Buffer { bytes, .. } => bytes.is_unique(),
+            Repr::Inline { .. } => true,
+        }
+    }
+
+    /// Returns `true` if `self` and `other` are views of the same buffer, such
+    /// as slices of one `ByteStr`.
+    ///
+    /// Inline strings are copied rather than shared, but each copy keeps the
+    /// inline string it was sliced from, and two inline strings are views of the
+    /// same buffer if these strings are equal. This compares contents, so it is
+    /// also `true` for inline strings that were created separately with the
+    /// same contents.
+    ///
+    /// Whether other strings share a buffer is not known if it was accessed
+    /// through [`as_bytes_mut`](Self::as_bytes_mut), or if they start more than
+    /// 4 GiB into it, so this returns `false` for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let log = ByteStr::from("12:00:00 INFO started");
+    /// let (time, rest) = log.split_once(' ').unwrap();
+    /// assert!(time.same_buffer(&rest));
+    /// assert!(!time.same_buffer(&ByteStr::from("12:00:00")));
+    /// ```
+    #[must_use]
+    pub fn same_buffer(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Inline { buf, .. }, Repr::Inline { buf: other, .. }) => buf == other,
+            _ => self
+                .buffer_origin()
+                .is_some_and(|origin| other.buffer_origin() == Some(origin)),
+        }
+    }
+
+    /// Joins two views of the same buffer, where `second` directly follows
+    /// `first`, into one view of both without copying.
+    ///
+    /// This reverses splitting a string, like `BytesMut::unsplit`. It returns
+    /// `None` if `second` does not start where `first` ends, or if they are not
+    /// known to be views of the same buffer (see
+    /// [`same_buffer`](Self::same_buffer)). An empty string is joined with
+    /// anything, giving back the other string.
+    ///
+    /// Non-empty inline strings are never joined, since whether they are of the
+    /// same string is only known from their contents. They are short, so
+    /// concatenating them instead is cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let line = ByteStr::from("Content-Type: text/html; charset=utf-8".repeat(2));
+    /// let (name, value) = line.clone().split_at(12);
+    /// let joined = ByteStr::try_join_adjacent(&name, &value).unwrap();
+    /// assert_eq!(joined, line);
+    ///
+    /// assert!(ByteStr::try_join_adjacent(&value, &name).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_join_adjacent(first: &Self, second: &Self) -> Option<Self> {
+        if first.is_empty() {
+            return Some(second.clone());
+        }
+        if second.is_empty() {
+            return Some(first.clone());
+        }
+        // Inline strings are only known to be of one string by their contents,
+        // see `same_buffer`, so they are never joined.
+        let (
+            Repr::Buffer {
+                bytes,
+                backing,
+                offset,
+            },
+            Repr::Buffer { bytes: next, .. },
+        ) = (&first.0, &second.0)
+        else {
+            return None;
+        };
+        if !first.same_buffer(second) || first.as_bytes().as_ptr_range().end != second.as_ptr() {
+            return None;
+        }
+
+        if *backing == STATIC {
+            // Both are views of the same `&'static str`.
+            let len = first.len() + second.len();
+            let joined: &'static [u8] = unsafe { core::slice::from_raw_parts(first.as_ptr(), len) };
+            return Some(Self(Repr::Buffer {
+                bytes: Bytes::from_static(joined),
+                backing: STATIC,
+                offset: *offset,
+            }));
+        }
+        Some(Self(Repr::Buffer {
+            bytes: Bytes::from_owner(Adjacent(bytes.clone(), next.clone())),
+            backing: *backing,
+            offset: *offset,
+        }))
+    }
+
+    /// Returns the inline string that `self` was sliced from, zero-padded, and
+    /// the offset of `self` in it, if `self` is stored inline.
+    const fn inline_root(&self) -> Option<(&[u8; Self::INLINE_CAPACITY], usize)> {
+        match &self.0 {
+            Repr::Inline { start, buf, .. } => Some((buf, *start as usize)),
+            Repr::Buffer { .. } => None,
+        }
+    }
+
+    /// Returns the address at which the buffer viewed by `self` starts and the
+    /// size of the buffer, or `STATIC` for a static string, if they are known.
+    fn buffer_origin(&self) -> Option<(usize, u32)> {
+        match self.0 {
+            Repr::Buffer {
+                ref bytes,
+                backing,
+                offset,
+            } if offset != UNKNOWN_OFFSET && backing != UNKNOWN_BACKING => Some((
+                (bytes.as_ptr() as usize).wrapping_sub(offset as usize),
+                backing,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Copies the string into a right-sized allocation if its buffer is larger
+    /// than the string itself, releasing this handle's hold on the old buffer.
+    ///
+    /// This is a no-op for static strings and for strings that already span
+    /// their whole buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let body = ByteStr::from("x".repeat(64 * 1024));
+    /// let mut head = body.slice(..10);
+    /// drop(body);
+    /// assert_eq!(head.capacity(), 64 * 1024);
+    ///
+    /// head.compact();
+    /// assert_eq!(head.capacity(), 10);
+    /// assert_eq!(head, "xxxxxxxxxx");
+    /// ```
+    pub fn compact(&mut self) {
+        if self.capacity() > self.len() {
+            *self = self.detach();
+        }
+    }
+
+    /// Returns a copy of the string in its own right-sized allocation, sharing
+    /// no buffer with `self`.
+    ///
+    /// Static strings are returned as-is, since they keep no memory alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let line = ByteStr::from(String::from("name: value"));
+    /// let name = line.slice(..4).detach();
+    /// assert_eq!(name, "name");
+    /// assert!(name.is_unique());
+    /// assert!(line.is_unique());
+    /// ```
+    #[must_use]
+    pub fn detach(&self) -> Self {
+        if self.is_static() {
+            self.clone()
+        } else {
+            Self::copy_from_str(self.as_str())
+        }
+    }
+
+    /// Returns a slice of self that is equivalent to the given subset, copying it
+    /// into its own allocation if it is smaller than `threshold` times this
+    /// `ByteStr`'s [capacity](Self::capacity).
+    ///
+    /// This keeps long-lived slices, such as cached header names, from pinning
+    /// the large buffers they were parsed from, while slices covering most of
+    /// their buffer stay zero-copy. A `threshold` of `0.0` never copies and one
+    /// of `1.0` or more always copies (except for static strings).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let request = ByteStr::from(format!("Host: example.com\r\n{}", "x".repeat(1000)));
+    /// let host = request.slice_ref_compacting(&request[6..17], 0.25);
+    /// assert_eq!(host, "example.com");
+    /// assert_eq!(host.capacity(), 11);
+    ///
+    /// let body = request.slice_ref_compacting(&request[19..], 0.25);
+    /// assert_eq!(body.capacity(), request.capacity());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `subset` is not contained within the `ByteStr`.
+    #[must_use]
+    pub fn slice_ref_compacting(&self, subset: &str, threshold: f64) -> Self {
+        let slice = self.slice_ref(subset);
+        #[allow(clippy::cast_precision_loss)]
+        let small = (slice.len() as f64) < threshold * self.capacity() as f64;
+        if small { slice.detach() } else { slice }
+    }
+
+    /// Returns `true` if the two `ByteStr`s view the same bytes of the same
+    /// buffer, without comparing their contents.
+    ///
+    /// This is a cheap way to compare handles from a [`ByteStrInterner`], which
+    /// hands out the same buffer for equal content. Inline strings are copied
+    /// when cloned, so they only compare equal to themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let a = ByteStr::from_static("shared");
+    /// let b = a.clone();
+    /// assert!(ByteStr::ptr_eq(&a, &b));
+    /// assert!(!ByteStr::ptr_eq(&a, &a.slice(..3)));
+    /// assert!(!ByteStr::ptr_eq(&a, &ByteStr::from("shared")));
+    /// ```
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.as_bytes().as_ptr() == other.as_bytes().as_ptr() && this.len() == other.len()
+    }
+}
+
+/// Two views of the same buffer, the second directly following the first, kept
+/// alive together by [`ByteStr::try_join_adjacent`].
+struct Adjacent(Bytes, Bytes);
+
+impl AsRef<[u8]> for Adjacent {
+    fn as_ref(&self) -> &[u8] {
+        // Both views are of one buffer, so the bytes from the start of the
+        // first to the end of the second are all part of it.
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr(), self.0.len() + self.1.len()) }
+    }
+}
+
+/// Resolves `range` against a string of `len` bytes, checking that it lies
+/// within the string. Char boundaries are left to the caller.
+pub(crate) fn resolve_range(
+    range: &impl RangeBounds<usize>,
+    len: usize,
+) -> Result<(usize, usize), SliceError> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => Some(n),
+        Bound::Excluded(&n) => n.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1),
+        Bound::Excluded(&n) => Some(n),
+        Bound::Unbounded => Some(len),
+    };
+
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(SliceError::OutOfBounds {
+            start: start.unwrap_or(usize::MAX),
+            end: end.unwrap_or(usize::MAX),
+            len,
+        });
+    };
+    if start > end || end > len {
+        return Err(SliceError::OutOfBounds { start, end, len });
+    }
+    Ok((start, end))
+}
+
+/// Feeds `bytes` to `state` in 64-byte blocks, followed by the `0xff` that
+/// `str` ends its hash with.
+///
+/// This is how the types that compare strings by something other than their
+/// bytes, or that store them in pieces, are hashed: it needs no allocation,
+/// doesn't call the hasher once per byte, and the calls the hasher sees depend
+/// only on the bytes, not on how they were produced. Up to 64 bytes are hashed
+/// exactly like a `str`.
+pub(crate) fn hash_in_blocks<H: Hasher>(state: &mut H, bytes: impl IntoIterator<Item = u8>) {
+    let mut block = [0; 64];
+    let mut filled = 0;
+    for byte in bytes {
+        if filled == block.len() {
+            state.write(&block);
+            filled = 0;
+        }
+        block[filled] = byte;
+        filled += 1;
     }
+    state.write(&block[..filled]);
+    state.write_u8(0xff);
 }
 
 #[cfg(test)]
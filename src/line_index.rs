@@ -0,0 +1,342 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{ByteStr, Location};
+
+/// A position in a [`LineIndex`], with the line and column both counted from 0.
+///
+/// The unit of the column depends on where the position comes from: bytes for
+/// [`LineIndex::line_col`] and [`LineIndex::offset`], UTF-16 code units for
+/// [`LineIndex::bytes_to_utf16`], and `char`s for [`LineIndex::bytes_to_chars`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    /// The line number, starting at 0.
+    pub line: usize,
+    /// The column number, starting at 0.
+    pub col: usize,
+}
+
+/// An index of the lines of a [`ByteStr`], mapping byte offsets to lines and
+/// columns and back.
+///
+/// The index is built once in a single pass over the string, and keeps a
+/// clone of it, which shares its buffer unless the string is stored inline.
+/// Lookups then take O(log n) time: the line is found by binary search over
+/// the line starts, and converting a column between bytes, `char`s and UTF-16
+/// code units (as used by the Language Server Protocol) only searches the
+/// non-ASCII characters of the string.
+///
+/// Lines are terminated by `\n`. A `\r` before it is part of the line for
+/// offsets and columns, but left out by [`line`](Self::line).
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, LineCol, LineIndex};
+///
+/// let index = LineIndex::new(ByteStr::from("let x = 1;\nlet π = \"ü\";\n"));
+///
+/// let position = index.line_col(20).unwrap();
+/// assert_eq!(position, LineCol { line: 1, col: 9 });
+/// assert_eq!(index.bytes_to_utf16(position), Some(LineCol { line: 1, col: 8 }));
+/// assert_eq!(index.offset(position), Some(20));
+/// assert_eq!(index.line(1).unwrap(), "let π = \"ü\";");
+/// ```
+#[derive(Clone)]
+pub struct LineIndex {
+    source: ByteStr,
+    /// The byte offset at which each line starts.
+    line_starts: Vec<usize>,
+    /// The non-ASCII characters of the source, in order.
+    non_ascii: Vec<NonAscii>,
+}
+
+/// A non-ASCII character, with the number of bytes by which the source up to
+/// and including it is longer than it is in other units.
+#[derive(Debug, Clone, Copy)]
+struct NonAscii {
+    end: usize,
+    utf16_skew: usize,
+    char_skew: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Utf16,
+    Char,
+}
+
+impl Unit {
+    const fn skew(self, c: &NonAscii) -> usize {
+        match self {
+            Self::Utf16 => c.utf16_skew,
+            Self::Char => c.char_skew,
+        }
+    }
+}
+
+impl LineIndex {
+    /// Builds the index of `source`.
+    #[must_use]
+    pub fn new(source: ByteStr) -> Self {
+        let mut line_starts = vec![0];
+        let mut non_ascii = Vec::new();
+        let (mut utf16_skew, mut char_skew) = (0, 0);
+        for (offset, c) in source.char_indices() {
+            if c == '\n' {
+                line_starts.push(offset + 1);
+            } else if !c.is_ascii() {
+                utf16_skew += c.len_utf8() - c.len_utf16();
+                char_skew += c.len_utf8() - 1;
+                non_ascii.push(NonAscii {
+                    end: offset + c.len_utf8(),
+                    utf16_skew,
+                    char_skew,
+                });
+            }
+        }
+
+        Self {
+            source,
+            line_starts,
+            non_ascii,
+        }
+    }
+
+    /// Returns the indexed string.
+    #[must_use]
+    pub const fn source(&self) -> &ByteStr {
+        &self.source
+    }
+
+    /// Returns the number of lines.
+    ///
+    /// This is one more than the number of `\n`s, so an empty string has one
+    /// empty line, and so does the end of a string ending with `\n`.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns line `n` as a zero-copy slice, without its line terminator, or
+    /// `None` if there is no such line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, LineIndex};
+    ///
+    /// let index = LineIndex::new(ByteStr::from("first\r\nsecond\n"));
+    /// assert_eq!(index.line(0).unwrap(), "first");
+    /// assert_eq!(index.line(1).unwrap(), "second");
+    /// assert_eq!(index.line(2).unwrap(), "");
+    /// assert!(index.line(3).is_none());
+    /// ```
+    #[must_use]
+    pub fn line(&self, n: usize) -> Option<ByteStr> {
+        let start = *self.line_starts.get(n)?;
+        let line = &self.source.as_str()[start..self.line_end(n)];
+        Some(
+            self.source
+                .slice_ref(line.strip_suffix('\r').unwrap_or(line)),
+        )
+    }
+
+    /// Returns the line and byte column of a byte offset, or `None` if the
+    /// offset is out of bounds or not on a `char` boundary.
+    ///
+    /// The end of the string is a valid offset.
+    #[must_use]
+    pub fn line_col(&self, offset: usize) -> Option<LineCol> {
+        if !self.source.is_char_boundary(offset) {
+            return None;
+        }
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        Some(LineCol {
+            line,
+            col: offset - self.line_starts[line],
+        })
+    }
+
+    /// Returns the byte offset of a line and byte column, or `None` if there
+    /// is no such line, the column is past the end of the line, or it is not
+    /// on a `char` boundary.
+    #[must_use]
+    pub fn offset(&self, position: LineCol) -> Option<usize> {
+        let start = *self.line_starts.get(position.line)?;
+        let offset = start.checked_add(position.col)?;
+        (offset <= self.line_end(position.line) && self.source.is_char_boundary(offset))
+            .then_some(offset)
+    }
+
+    /// Returns the location of a byte offset, with the line and `char` column
+    /// counted from 1, like [`ByteStrCursor::location`](crate::ByteStrCursor::location).
+    ///
+    /// Returns `None` if the offset is out of bounds or not on a `char`
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, LineIndex};
+    ///
+    /// let index = LineIndex::new(ByteStr::from("a = 1\nbé = 2"));
+    /// assert_eq!(index.location(10).unwrap().to_string(), "2:4");
+    /// ```
+    #[must_use]
+    pub fn location(&self, offset: usize) -> Option<Location> {
+        let position = self.bytes_to_chars(self.line_col(offset)?)?;
+        Some(Location::new(offset, position.line + 1, position.col + 1))
+    }
+
+    /// Returns the byte offset at which `subset` starts in the indexed string,
+    /// or `None` if it is not a slice of it.
+    ///
+    /// This resolves the slices returned by [`ByteStr::slice_ref`] and other
+    /// zero-copy methods to their position, using [`ByteStr::offset_in`]. The
+    /// slice must be taken from the string the index was built from, from
+    /// [`source`](Self::source), or from a clone of either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, LineIndex};
+    ///
+    /// let index = LineIndex::new(ByteStr::from("fn main() {\n    let answer = 42;\n}\n"));
+    /// let source = index.source();
+    ///
+    /// let token = source.split_whitespace().nth(4).unwrap();
+    /// assert_eq!(token, "answer");
+    /// let offset = index.offset_of(&token).unwrap();
+    /// assert_eq!(index.location(offset).unwrap().to_string(), "2:9");
+    ///
+    /// assert_eq!(index.offset_of(&ByteStr::from("answer")), None);
+    /// ```
+    #[must_use]
+    pub fn offset_of(&self, subset: &ByteStr) -> Option<usize> {
+        subset.offset_in(&self.source).map(|range| range.start)
+    }
+
+    /// Converts a line and byte column to a line and UTF-16 column, or
+    /// returns `None` if the position is not valid (see
+    /// [`offset`](Self::offset)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, LineCol, LineIndex};
+    ///
+    /// let index = LineIndex::new(ByteStr::from("😀 = 1"));
+    /// let position = LineCol { line: 0, col: 5 };
+    /// assert_eq!(index.bytes_to_utf16(position), Some(LineCol { line: 0, col: 3 }));
+    /// ```
+    #[must_use]
+    pub fn bytes_to_utf16(&self, position: LineCol) -> Option<LineCol> {
+        self.bytes_to_unit(position, Unit::Utf16)
+    }
+
+    /// Converts a line and UTF-16 column to a line and byte column, or
+    /// returns `None` if there is no such line, the column is past the end of
+    /// the line, or it falls inside a surrogate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteStr, LineCol, LineIndex};
+    ///
+    /// let index = LineIndex::new(ByteStr::from("😀 = 1"));
+    /// assert_eq!(index.utf16_to_bytes(LineCol { line: 0, col: 3 }), Some(LineCol { line: 0, col: 5 }));
+    /// assert_eq!(index.utf16_to_bytes(LineCol { line: 0, col: 1 }), None);
+    /// ```
+    #[must_use]
+    pub fn utf16_to_bytes(&self, position: LineCol) -> Option<LineCol> {
+        self.unit_to_bytes(position, Unit::Utf16)
+    }
+
+    /// Converts a line and byte column to a line and `char` column, or returns
+    /// `None` if the position is not valid (see [`offset`](Self::offset)).
+    #[must_use]
+    pub fn bytes_to_chars(&self, position: LineCol) -> Option<LineCol> {
+        self.bytes_to_unit(position, Unit::Char)
+    }
+
+    /// Converts a line and `char` column to a line and byte column, or returns
+    /// `None` if there is no such line or the column is past the end of the
+    /// line.
+    #[must_use]
+    pub fn chars_to_bytes(&self, position: LineCol) -> Option<LineCol> {
+        self.unit_to_bytes(position, Unit::Char)
+    }
+
+    /// Returns the offset of the `\n` ending line `n`, or the length of the
+    /// string for the last line.
+    fn line_end(&self, n: usize) -> usize {
+        self.line_starts
+            .get(n + 1)
+            .map_or(self.source.len(), |next| next - 1)
+    }
+
+    /// Returns the number of non-ASCII characters before `offset`.
+    fn non_ascii_before(&self, offset: usize) -> usize {
+        self.non_ascii.partition_point(|c| c.end <= offset)
+    }
+
+    /// Returns how many more bytes than `unit`s the first `count` non-ASCII
+    /// characters take.
+    fn skew_of(&self, count: usize, unit: Unit) -> usize {
+        count
+            .checked_sub(1)
+            .map_or(0, |last| unit.skew(&self.non_ascii[last]))
+    }
+
+    fn bytes_to_unit(&self, position: LineCol, unit: Unit) -> Option<LineCol> {
+        let offset = self.offset(position)?;
+        let start = self.line_starts[position.line];
+        let skew = self.skew_of(self.non_ascii_before(offset), unit)
+            - self.skew_of(self.non_ascii_before(start), unit);
+        Some(LineCol {
+            line: position.line,
+            col: position.col - skew,
+        })
+    }
+
+    fn unit_to_bytes(&self, position: LineCol, unit: Unit) -> Option<LineCol> {
+        let start = *self.line_starts.get(position.line)?;
+        let first = self.non_ascii_before(start);
+        let base = self.skew_of(first, unit);
+        let line = &self.non_ascii[first..self.non_ascii_before(self.line_end(position.line))];
+
+        // Count the characters of the line that end at or before the column,
+        // measuring their ends in `unit`s from the start of the line.
+        let passed =
+            line.partition_point(|c| c.end - start - (unit.skew(c) - base) <= position.col);
+        let skew = self.skew_of(first + passed, unit) - base;
+        let col = position.col.checked_add(skew)?;
+
+        // A column inside a character lands off a `char` boundary here.
+        self.offset(LineCol {
+            line: position.line,
+            col,
+        })?;
+        Some(LineCol {
+            line: position.line,
+            col,
+        })
+    }
+}
+
+impl fmt::Debug for LineIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineIndex")
+            .field("source", &self.source)
+            .field("lines", &self.line_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<ByteStr> for LineIndex {
+    fn from(source: ByteStr) -> Self {
+        Self::new(source)
+    }
+}
@@ -0,0 +1,146 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+
+use crate::{ByteStr, Spanned};
+
+/// A `ByteStr` parser input that keeps track of its byte offset in the
+/// original source.
+///
+/// Parsing a `ByteStr` with `nom` or `winnow` yields tokens that are slices of
+/// the input, but does not say where they were found. Parsing a `Located`
+/// instead advances the offset as the input is consumed, and every token it
+/// yields is itself a `Located` that knows its position, which
+/// [`Located::span`] turns into a range for diagnostics.
+///
+/// Like [`Spanned`], comparison and hashing only look at the input and ignore
+/// the offset, so a token equals the same text found anywhere else.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, Located};
+///
+/// // A fragment that starts at byte 13 of its source file.
+/// let number = Located::with_offset(ByteStr::from("42"), 13);
+/// assert_eq!(number.span(), 13..15);
+///
+/// assert_eq!(number, Located::new(ByteStr::from("42")));
+///
+/// let spanned = number.into_spanned();
+/// assert_eq!(spanned, "42");
+/// assert_eq!(spanned.span(), 13..15);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Located {
+    input: ByteStr,
+    offset: usize,
+}
+
+impl Located {
+    /// Wraps `input` to be parsed from offset 0.
+    #[must_use]
+    pub const fn new(input: ByteStr) -> Self {
+        Self::with_offset(input, 0)
+    }
+
+    /// Wraps `input` to be parsed as if it started at byte `offset` of its
+    /// source, for instance when parsing one line of a larger file.
+    #[must_use]
+    pub const fn with_offset(input: ByteStr, offset: usize) -> Self {
+        Self { input, offset }
+    }
+
+    /// Returns the byte offset of the start of the input in its source.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns a reference to the input.
+    #[must_use]
+    pub const fn get(&self) -> &ByteStr {
+        &self.input
+    }
+
+    /// Returns the input as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.input.as_str()
+    }
+
+    /// Returns the byte range of the source that the input covers.
+    #[must_use]
+    pub const fn span(&self) -> Range<usize> {
+        self.offset..self.offset + self.input.len()
+    }
+
+    /// Consumes the wrapper, returning the input.
+    #[must_use]
+    pub fn into_inner(self) -> ByteStr {
+        self.input
+    }
+
+    /// Consumes the wrapper, returning the input together with its span.
+    #[must_use]
+    pub fn into_spanned(self) -> Spanned {
+        let span = self.span();
+        Spanned::new(self.input, span)
+    }
+
+    /// Returns the part of the input within `range`, keeping track of its
+    /// offset.
+    pub(crate) fn slice(&self, range: Range<usize>) -> Self {
+        Self::with_offset(self.input.slice(range.clone()), self.offset + range.start)
+    }
+}
+
+impl From<ByteStr> for Located {
+    fn from(input: ByteStr) -> Self {
+        Self::new(input)
+    }
+}
+
+impl From<Located> for Spanned {
+    fn from(located: Located) -> Self {
+        located.into_spanned()
+    }
+}
+
+impl AsRef<str> for Located {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Located {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Located {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input
+    }
+}
+
+impl Eq for Located {}
+
+impl Hash for Located {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+    }
+}
+
+impl PartialEq<str> for Located {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Located {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::string::String;
+
+use nom::{
+    AsBytes, Compare, CompareResult, ExtendInto, FindSubstring, Input, Needed, Offset, ParseTo,
+};
+
+use crate::iter::{IntoCharIndices, IntoChars};
+use crate::{ByteStr, Located};
+
+// `ByteStr` is parsed like `&str`, by `char`s, and every slice taken from it is
+// a view of the input, which `ByteStr::offset_in` can locate, even for inline
+// inputs whose slices are copies. Offsets are found the same way, so they work
+// for inline inputs as well.
+impl Input for ByteStr {
+    type Item = char;
+    type Iter = IntoChars;
+    type IterIndices = IntoCharIndices;
+
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    fn take(&self, index: usize) -> Self {
+        self.slice(..index)
+    }
+
+    fn take_from(&self, index: usize) -> Self {
+        self.slice(index..)
+    }
+
+    fn take_split(&self, index: usize) -> (Self, Self) {
+        let (prefix, suffix) = self.clone().split_at(index);
+        (suffix, prefix)
+    }
+
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        self.as_str().position(predicate)
+    }
+
+    fn iter_elements(&self) -> Self::Iter {
+        self.clone().into_chars()
+    }
+
+    fn iter_indices(&self) -> Self::IterIndices {
+        self.clone().into_char_indices()
+    }
+
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.as_str().slice_index(count)
+    }
+}
+
+// Panics if `second` is not a slice of `self`, which nom never passes.
+impl Offset for ByteStr {
+    fn offset(&self, second: &Self) -> usize {
+        second
+            .offset_in(self)
+            .expect("the second input is not a slice of the first")
+            .start
+    }
+}
+
+// Tokens taken from a `Located` input are `Located` as well, with the offset
+// of the slice added to that of the input.
+impl Input for Located {
+    type Item = char;
+    type Iter = IntoChars;
+    type IterIndices = IntoCharIndices;
+
+    fn input_len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    fn take(&self, index: usize) -> Self {
+        self.slice(0..index)
+    }
+
+    fn take_from(&self, index: usize) -> Self {
+        self.slice(index..self.input_len())
+    }
+
+    fn take_split(&self, index: usize) -> (Self, Self) {
+        (self.take_from(index), self.take(index))
+    }
+
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        self.as_str().position(predicate)
+    }
+
+    fn iter_elements(&self) -> Self::Iter {
+        self.get().clone().into_chars()
+    }
+
+    fn iter_indices(&self) -> Self::IterIndices {
+        self.get().clone().into_char_indices()
+    }
+
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.as_str().slice_index(count)
+    }
+}
+
+// Panics if `second` starts before `self`, which nom never passes.
+impl Offset for Located {
+    fn offset(&self, second: &Self) -> usize {
+        second
+            .offset()
+            .checked_sub(self.offset())
+            .expect("the second input starts before the first")
+    }
+}
+
+// Implements the traits that only look at the contents of the input by
+// delegating to their `&str` implementations.
+macro_rules! impl_str_traits {
+    ($($ty:ty),*) => {$(
+        impl AsBytes for $ty {
+            fn as_bytes(&self) -> &[u8] {
+                self.as_str().as_bytes()
+            }
+        }
+
+        impl<T> Compare<T> for $ty
+        where
+            for<'a> &'a str: Compare<T>,
+        {
+            fn compare(&self, t: T) -> CompareResult {
+                self.as_str().compare(t)
+            }
+
+            fn compare_no_case(&self, t: T) -> CompareResult {
+                self.as_str().compare_no_case(t)
+            }
+        }
+
+        impl<T> FindSubstring<T> for $ty
+        where
+            for<'a> &'a str: FindSubstring<T>,
+        {
+            fn find_substring(&self, substr: T) -> Option<usize> {
+                self.as_str().find_substring(substr)
+            }
+        }
+
+        impl<R> ParseTo<R> for $ty
+        where
+            for<'a> &'a str: ParseTo<R>,
+        {
+            fn parse_to(&self) -> Option<R> {
+                self.as_str().parse_to()
+            }
+        }
+
+        impl ExtendInto for $ty {
+            type Item = char;
+            type Extender = String;
+
+            fn new_builder(&self) -> Self::Extender {
+                String::new()
+            }
+
+            fn extend_into(&self, acc: &mut Self::Extender) {
+                acc.push_str(self.as_str());
+            }
+        }
+    )*};
+}
+
+impl_str_traits!(ByteStr, Located);
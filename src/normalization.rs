@@ -0,0 +1,239 @@
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{ByteStr, hash_in_blocks};
+
+// The conversions first check whether the string already is in the requested
+// form, which is fast for the common case of text that is, and return a clone
+// sharing the buffer if so.
+impl ByteStr {
+    /// Returns the string in Normalization Form C (canonical composition).
+    ///
+    /// If the string already is in NFC, this returns a clone sharing the same
+    /// buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let decomposed = ByteStr::from("Cafe\u{301}");
+    /// assert_eq!(decomposed.nfc(), "Caf\u{e9}");
+    ///
+    /// let composed = ByteStr::from_static("Caf\u{e9}");
+    /// assert!(ByteStr::ptr_eq(&composed.nfc(), &composed));
+    /// ```
+    #[must_use]
+    pub fn nfc(&self) -> Self {
+        if self.is_nfc() {
+            return self.clone();
+        }
+        Self::from(self.as_str().nfc().collect::<String>())
+    }
+
+    /// Returns the string in Normalization Form D (canonical decomposition).
+    ///
+    /// If the string already is in NFD, this returns a clone sharing the same
+    /// buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// assert_eq!(ByteStr::from("Caf\u{e9}").nfd(), "Cafe\u{301}");
+    /// ```
+    #[must_use]
+    pub fn nfd(&self) -> Self {
+        if self.is_nfd() {
+            return self.clone();
+        }
+        Self::from(self.as_str().nfd().collect::<String>())
+    }
+
+    /// Returns the string in Normalization Form KC (compatibility composition).
+    ///
+    /// If the string already is in NFKC, this returns a clone sharing the same
+    /// buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// assert_eq!(ByteStr::from("\u{fb01}le").nfkc(), "file");
+    /// ```
+    #[must_use]
+    pub fn nfkc(&self) -> Self {
+        if self.is_nfkc() {
+            return self.clone();
+        }
+        Self::from(self.as_str().nfkc().collect::<String>())
+    }
+
+    /// Returns the string in Normalization Form KD (compatibility decomposition).
+    ///
+    /// If the string already is in NFKD, this returns a clone sharing the same
+    /// buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// assert_eq!(ByteStr::from("\u{fb01}anc\u{e9}").nfkd(), "fiance\u{301}");
+    /// ```
+    #[must_use]
+    pub fn nfkd(&self) -> Self {
+        if self.is_nfkd() {
+            return self.clone();
+        }
+        Self::from(self.as_str().nfkd().collect::<String>())
+    }
+
+    /// Returns `true` if the string is in Normalization Form C.
+    #[must_use]
+    pub fn is_nfc(&self) -> bool {
+        unicode_normalization::is_nfc(self)
+    }
+
+    /// Returns `true` if the string is in Normalization Form D.
+    #[must_use]
+    pub fn is_nfd(&self) -> bool {
+        unicode_normalization::is_nfd(self)
+    }
+
+    /// Returns `true` if the string is in Normalization Form KC.
+    #[must_use]
+    pub fn is_nfkc(&self) -> bool {
+        unicode_normalization::is_nfkc(self)
+    }
+
+    /// Returns `true` if the string is in Normalization Form KD.
+    #[must_use]
+    pub fn is_nfkd(&self) -> bool {
+        unicode_normalization::is_nfkd(self)
+    }
+}
+
+/// A string wrapper whose comparison and hashing ignore differences in
+/// Unicode normalization.
+///
+/// Two strings compare equal if they are canonically equivalent, such as
+/// `"é"` written as one precomposed character or as `"e"` followed by a
+/// combining accent. The comparison decomposes both strings on the fly, so it
+/// never allocates, and the original form is kept for display and
+/// [`get`](Self::get). Compatibility equivalents, such as the `"ﬁ"` ligature
+/// and `"fi"`, remain different; apply [`ByteStr::nfkc`] first to fold them.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use bytestr::{ByteStr, Normalized};
+///
+/// let mut users = HashSet::new();
+/// users.insert(Normalized::new(ByteStr::from("Zo\u{eb}")));
+///
+/// assert!(users.contains(&Normalized::new(ByteStr::from("Zoe\u{308}"))));
+/// assert!(!users.insert(Normalized::new(ByteStr::from("Zoe\u{308}"))));
+/// assert_eq!(users.iter().next().unwrap().to_string(), "Zo\u{eb}");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Normalized<T = ByteStr>(T);
+
+impl<T> Normalized<T> {
+    /// Wraps `value` so that it is compared ignoring normalization.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value, in its original form.
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the wrapper, returning the wrapped value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> Normalized<T> {
+    /// Returns the wrapped value as a `&str`, in its original form.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    fn decomposed(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_str().nfd()
+    }
+}
+
+impl<T> From<T> for Normalized<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<str>> AsRef<str> for Normalized<T> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Normalized<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialEq<Normalized<U>> for Normalized<T> {
+    fn eq(&self, other: &Normalized<U>) -> bool {
+        self.as_str() == other.as_str() || self.decomposed().eq(other.decomposed())
+    }
+}
+
+impl<T: AsRef<str>> Eq for Normalized<T> {}
+
+impl<T: AsRef<str>> PartialOrd for Normalized<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for Normalized<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.decomposed().cmp(other.decomposed())
+    }
+}
+
+impl<T: AsRef<str>> Hash for Normalized<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bytes = self.decomposed().flat_map(|c| {
+            let mut buf = [0; 4];
+            let len = c.encode_utf8(&mut buf).len();
+            buf.into_iter().take(len)
+        });
+        hash_in_blocks(state, bytes);
+    }
+}
+
+impl<T: AsRef<str>> PartialEq<str> for Normalized<T> {
+    fn eq(&self, other: &str) -> bool {
+        *self == Normalized::new(other)
+    }
+}
+
+impl<T: AsRef<str>> PartialEq<&str> for Normalized<T> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == Normalized::new(*other)
+    }
+}
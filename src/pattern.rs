@@ -0,0 +1,167 @@
+use alloc::string::String;
+
+/// A string pattern accepted by the searching and splitting methods of [`ByteStr`](crate::ByteStr).
+///
+/// This is a stable stand-in for the unstable `core::str::pattern::Pattern` and is
+/// implemented for the same types:
+///
+/// | Pattern type             | Match condition                          |
+/// |--------------------------|------------------------------------------|
+/// | `&str`                   | is substring                             |
+/// | `char`                   | is contained in string                   |
+/// | `&[char]`                | any char in slice is contained in string |
+/// | `[char; N]`, `&[char; N]`| any char in array is contained in string |
+/// | `F: FnMut(char) -> bool` | `F` returns `true` for a char in string  |
+/// | `&&str`, `&String`       | is substring                             |
+///
+/// The trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::ByteStr;
+///
+/// let s = ByteStr::from("a-b_c");
+/// assert_eq!(s.split(['-', '_']).collect::<Vec<_>>(), ["a", "b", "c"]);
+/// assert_eq!(s.split_once(|c: char| !c.is_alphabetic()).unwrap().0, "a");
+/// ```
+pub trait Pattern: sealed::Sealed {
+    /// Returns the byte range of the first match of the pattern in `haystack`.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range of the last match of the pattern in `haystack`.
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns `haystack` without the pattern if it matches at the front.
+    fn strip_prefix_of<'a>(&mut self, haystack: &'a str) -> Option<&'a str>;
+
+    /// Returns `haystack` without the pattern if it matches at the back.
+    fn strip_suffix_of<'a>(&mut self, haystack: &'a str) -> Option<&'a str>;
+}
+
+/// A [`Pattern`] whose forward and backward searches find the same matches.
+///
+/// This holds for every pattern that matches single characters, but not for
+/// `&str`: splitting `"aaa"` on `"aa"` gives different results from each end.
+/// Iterators that can be consumed from both ends require this bound, just like
+/// `str`'s iterators require a `DoubleEndedSearcher`.
+pub trait DoubleEndedPattern: Pattern {}
+
+impl DoubleEndedPattern for char {}
+impl DoubleEndedPattern for &[char] {}
+impl<const N: usize> DoubleEndedPattern for [char; N] {}
+impl<const N: usize> DoubleEndedPattern for &[char; N] {}
+impl<F: FnMut(char) -> bool> DoubleEndedPattern for F {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+// Every pattern type is forwarded to the matching `str` method, `$pat` being
+// bound to `&mut self` and `$std` the expression that turns it into a std pattern.
+macro_rules! impl_pattern {
+    ($([$($generics:tt)*] $ty:ty => |$pat:ident| $std:expr;)*) => {$(
+        impl<$($generics)*> sealed::Sealed for $ty {}
+
+        impl<$($generics)*> Pattern for $ty {
+            fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+                let $pat = self;
+                haystack
+                    .match_indices($std)
+                    .next()
+                    .map(|(i, m)| (i, i + m.len()))
+            }
+
+            fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+                let $pat = self;
+                haystack
+                    .rmatch_indices($std)
+                    .next()
+                    .map(|(i, m)| (i, i + m.len()))
+            }
+
+            fn strip_prefix_of<'a>(&mut self, haystack: &'a str) -> Option<&'a str> {
+                let $pat = self;
+                haystack.strip_prefix($std)
+            }
+
+            fn strip_suffix_of<'a>(&mut self, haystack: &'a str) -> Option<&'a str> {
+                let $pat = self;
+                haystack.strip_suffix($std)
+            }
+        }
+    )*};
+}
+
+impl_pattern! {
+    [] char => |p| *p;
+    ['b] &'b str => |p| *p;
+    ['b, 'c] &'c &'b str => |p| *p;
+    ['b] &'b String => |p| p.as_str();
+    ['b] &'b [char] => |p| *p;
+    [const N: usize] [char; N] => |p| *p;
+    ['b, const N: usize] &'b [char; N] => |p| *p;
+    [F: FnMut(char) -> bool] F => |p| &mut *p;
+}
+
+/// A stateful search over a haystack that reports non-overlapping matches from
+/// both ends, mirroring the searchers behind `str`'s own iterators.
+///
+/// The haystack itself is passed to every call so that the owner can keep it
+/// in a `ByteStr` without self-borrowing.
+#[derive(Clone)]
+pub struct Searcher<P> {
+    pat: P,
+    position: usize,
+    end: usize,
+    // Empty patterns match at every char boundary; these record whether an empty
+    // match was just reported at `position` / `end` so it is not reported twice.
+    empty_at_position: bool,
+    empty_at_end: bool,
+}
+
+impl<P: Pattern> Searcher<P> {
+    pub const fn new(pat: P, len: usize) -> Self {
+        Self {
+            pat,
+            position: 0,
+            end: len,
+            empty_at_position: false,
+            empty_at_end: false,
+        }
+    }
+
+    pub fn next_match(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let mut from = self.position;
+        if self.empty_at_position {
+            from += haystack[from..self.end].chars().next()?.len_utf8();
+        }
+
+        let (a, b) = self.pat.find_in(&haystack[from..self.end])?;
+        let (a, b) = (a + from, b + from);
+        if a == b && a == self.end && self.empty_at_end {
+            return None;
+        }
+
+        self.position = b;
+        self.empty_at_position = a == b;
+        Some((a, b))
+    }
+
+    pub fn next_match_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let mut to = self.end;
+        if self.empty_at_end {
+            to -= haystack[self.position..to].chars().next_back()?.len_utf8();
+        }
+
+        let (a, b) = self.pat.rfind_in(&haystack[self.position..to])?;
+        let (a, b) = (a + self.position, b + self.position);
+        if a == b && a == self.position && self.empty_at_position {
+            return None;
+        }
+
+        self.end = a;
+        self.empty_at_end = a == b;
+        Some((a, b))
+    }
+}
@@ -0,0 +1,442 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::RangeBounds;
+
+use crate::iter::{RopeChars, RopeLines};
+use crate::{ByteStr, SliceError, hash_in_blocks, resolve_range};
+
+/// A string made of a sequence of [`ByteStr`] segments, concatenated without
+/// copying.
+///
+/// Building a message out of many slices normally means copying them into a
+/// fresh `String`. A `ByteRope` instead keeps the (cheaply cloned) segments
+/// around and only copies them into one buffer when [`flatten`](Self::flatten)
+/// is called, if at all. Byte offsets, slicing, iteration, comparison and
+/// hashing all behave as if the rope was the flattened string.
+///
+/// Empty segments are never stored.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteRope, ByteStr};
+///
+/// let request = ByteStr::from_static("GET /index.html HTTP/1.1");
+/// let mut rope = ByteRope::new();
+/// rope.push(request.slice(..3));
+/// rope.push(ByteStr::from_static(" "));
+/// rope.push(request.slice(4..15));
+///
+/// assert_eq!(rope, "GET /index.html");
+/// assert_eq!(rope.segments().len(), 3);
+/// assert_eq!(rope.slice(2..5), "T /");
+/// assert_eq!(rope.flatten(), "GET /index.html");
+/// ```
+#[derive(Clone, Default)]
+pub struct ByteRope {
+    segments: Vec<ByteStr>,
+    len: usize,
+}
+
+impl ByteRope {
+    /// Creates an empty rope.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the total length of the rope in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the rope has a length of zero bytes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the segments making up the rope, in order.
+    #[must_use]
+    pub fn segments(&self) -> &[ByteStr] {
+        &self.segments
+    }
+
+    /// Appends a segment to the end of the rope without copying it.
+    pub fn push(&mut self, segment: ByteStr) {
+        if !segment.is_empty() {
+            self.len += segment.len();
+            self.segments.push(segment);
+        }
+    }
+
+    /// Appends all segments of `other` to the end of the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteRope, ByteStr};
+    ///
+    /// let mut head = ByteRope::from(ByteStr::from("Hello, "));
+    /// let tail: ByteRope = ["world", "!"].into_iter().map(ByteStr::from).collect();
+    /// head.concat(tail);
+    /// assert_eq!(head, "Hello, world!");
+    /// ```
+    pub fn concat(&mut self, other: Self) {
+        self.len += other.len;
+        self.segments.extend(other.segments);
+    }
+
+    /// Inserts a segment at byte offset `index`, splitting the segment that
+    /// contains it if needed. Nothing is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the rope, or if it is not
+    /// on a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteRope, ByteStr};
+    ///
+    /// let mut rope = ByteRope::from(ByteStr::from("Hello world"));
+    /// rope.insert(5, ByteStr::from(","));
+    /// assert_eq!(rope, "Hello, world");
+    /// assert_eq!(rope.segments().len(), 3);
+    /// ```
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, segment: ByteStr) {
+        if let Err(e) = self.check_index(index) {
+            panic!("{e}");
+        }
+        if segment.is_empty() {
+            return;
+        }
+
+        let (position, offset) = self.locate(index);
+        let len = segment.len();
+        if offset == 0 {
+            self.segments.insert(position, segment);
+        } else {
+            let split = &self.segments[position];
+            let (head, tail) = (split.slice(..offset), split.slice(offset..));
+            self.segments[position] = head;
+            self.segments.insert(position + 1, tail);
+            self.segments.insert(position + 1, segment);
+        }
+        self.len += len;
+    }
+
+    /// Returns the bytes in `range` as a new rope sharing the segments of self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if either end is not on a UTF-8
+    /// code point boundary. See [`ByteRope::try_slice`] for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteRope, ByteStr};
+    ///
+    /// let rope: ByteRope = ["ab", "cd", "ef"].into_iter().map(ByteStr::from).collect();
+    /// let middle = rope.slice(1..5);
+    /// assert_eq!(middle, "bcde");
+    /// assert_eq!(middle.segments(), ["b", "cd", "e"]);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        match self.try_slice(range) {
+            Ok(slice) => slice,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Returns the bytes in `range` as a new rope sharing the segments of self.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::OutOfBounds`] if the range does not lie within the
+    /// rope, and [`SliceError::NotCharBoundary`] if either end falls inside a
+    /// multi-byte UTF-8 sequence.
+    pub fn try_slice(&self, range: impl RangeBounds<usize>) -> Result<Self, SliceError> {
+        let (start, end) = resolve_range(&range, self.len)?;
+        self.check_index(start)?;
+        self.check_index(end)?;
+
+        let mut slice = Self::new();
+        let mut segment_start = 0;
+        for segment in &self.segments {
+            let segment_end = segment_start + segment.len();
+            if segment_end > start && segment_start < end {
+                let from = start.saturating_sub(segment_start);
+                let to = end.min(segment_end) - segment_start;
+                slice.push(segment.slice(from..to));
+            }
+            if segment_end >= end {
+                break;
+            }
+            segment_start = segment_end;
+        }
+        Ok(slice)
+    }
+
+    /// Returns `true` if `index` is on a UTF-8 code point boundary. The start
+    /// and end of the rope are boundaries.
+    #[must_use]
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        self.check_index(index).is_ok()
+    }
+
+    /// Returns an iterator over the `char`s of the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteRope, ByteStr};
+    ///
+    /// let rope: ByteRope = ["h", "é", "llo"].into_iter().map(ByteStr::from).collect();
+    /// assert!(rope.chars().eq("héllo".chars()));
+    /// assert_eq!(rope.chars().next_back(), Some('o'));
+    /// ```
+    #[must_use]
+    pub fn chars(&self) -> RopeChars<'_> {
+        RopeChars::new(&self.segments)
+    }
+
+    /// Returns an iterator over the lines of the rope, as ropes.
+    ///
+    /// Lines are split like [`str::lines`] does, so a line may span several
+    /// segments and a `\r\n` line ending is recognized even if it is split
+    /// between two of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::{ByteRope, ByteStr};
+    ///
+    /// let rope: ByteRope = ["first\r", "\nsec", "ond\n"].into_iter().map(ByteStr::from).collect();
+    /// let lines: Vec<ByteRope> = rope.lines().collect();
+    /// assert_eq!(lines, ["first", "second"]);
+    /// ```
+    #[must_use]
+    pub fn lines(&self) -> RopeLines<'_> {
+        RopeLines::new(&self.segments)
+    }
+
+    /// Joins the segments into a single `ByteStr`.
+    ///
+    /// A rope with a single segment returns it without copying; otherwise the
+    /// segments are copied into a new buffer.
+    #[must_use]
+    pub fn flatten(&self) -> ByteStr {
+        match self.segments.as_slice() {
+            [] => ByteStr::new(),
+            [segment] => segment.clone(),
+            segments => {
+                let mut flat = String::with_capacity(self.len);
+                for segment in segments {
+                    flat.push_str(segment);
+                }
+                ByteStr::from(flat)
+            }
+        }
+    }
+
+    /// Returns the position of the segment containing byte `index`, and the
+    /// offset of `index` in it. An index at the end of the rope maps past the
+    /// last segment.
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let mut segment_start = 0;
+        for (position, segment) in self.segments.iter().enumerate() {
+            if index < segment_start + segment.len() {
+                return (position, index - segment_start);
+            }
+            segment_start += segment.len();
+        }
+        (self.segments.len(), 0)
+    }
+
+    /// Removes a trailing carriage return, if there is one.
+    pub(crate) fn strip_carriage_return(&mut self) {
+        if let Some(last) = self.segments.last_mut() {
+            if let Some(stripped) = last.strip_suffix('\r') {
+                *last = stripped;
+                self.len -= 1;
+                if last.is_empty() {
+                    self.segments.pop();
+                }
+            }
+        }
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), SliceError> {
+        if index > self.len {
+            return Err(SliceError::OutOfBounds {
+                start: index,
+                end: index,
+                len: self.len,
+            });
+        }
+        let (position, offset) = self.locate(index);
+        if offset != 0 && !self.segments[position].is_char_boundary(offset) {
+            return Err(SliceError::NotCharBoundary { index });
+        }
+        Ok(())
+    }
+
+    fn chunks(&self) -> impl Iterator<Item = &[u8]> {
+        self.segments.iter().map(ByteStr::as_bytes)
+    }
+}
+
+/// Compares two byte strings given as sequences of chunks, regardless of how
+/// each of them is split.
+fn cmp_chunks<'a>(
+    mut left: impl Iterator<Item = &'a [u8]>,
+    mut right: impl Iterator<Item = &'a [u8]>,
+) -> Ordering {
+    let (mut lhs, mut rhs): (&[u8], &[u8]) = (&[], &[]);
+    loop {
+        if lhs.is_empty() {
+            lhs = left.next().unwrap_or_default();
+        }
+        if rhs.is_empty() {
+            rhs = right.next().unwrap_or_default();
+        }
+        if lhs.is_empty() || rhs.is_empty() {
+            return lhs.len().cmp(&rhs.len());
+        }
+
+        let common = lhs.len().min(rhs.len());
+        match lhs[..common].cmp(&rhs[..common]) {
+            Ordering::Equal => (lhs, rhs) = (&lhs[common..], &rhs[common..]),
+            unequal => return unequal,
+        }
+    }
+}
+
+impl From<ByteStr> for ByteRope {
+    fn from(segment: ByteStr) -> Self {
+        let mut rope = Self::new();
+        rope.push(segment);
+        rope
+    }
+}
+
+impl From<ByteRope> for ByteStr {
+    fn from(rope: ByteRope) -> Self {
+        rope.flatten()
+    }
+}
+
+impl FromIterator<ByteStr> for ByteRope {
+    fn from_iter<I: IntoIterator<Item = ByteStr>>(iter: I) -> Self {
+        let mut rope = Self::new();
+        rope.extend(iter);
+        rope
+    }
+}
+
+impl Extend<ByteStr> for ByteRope {
+    fn extend<I: IntoIterator<Item = ByteStr>>(&mut self, iter: I) {
+        for segment in iter {
+            self.push(segment);
+        }
+    }
+}
+
+impl fmt::Debug for ByteRope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.segments).finish()
+    }
+}
+
+impl fmt::Display for ByteRope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.segments
+            .iter()
+            .try_for_each(|segment| f.write_str(segment))
+    }
+}
+
+// Comparison and hashing must agree with the flattened string, whatever the
+// segments are.
+
+impl PartialEq for ByteRope {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && cmp_chunks(self.chunks(), other.chunks()).is_eq()
+    }
+}
+
+impl Eq for ByteRope {}
+
+impl PartialOrd for ByteRope {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteRope {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_chunks(self.chunks(), other.chunks())
+    }
+}
+
+impl Hash for ByteRope {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_in_blocks(state, self.chunks().flatten().copied());
+    }
+}
+
+impl PartialEq<str> for ByteRope {
+    fn eq(&self, other: &str) -> bool {
+        self.len == other.len()
+            && cmp_chunks(self.chunks(), core::iter::once(other.as_bytes())).is_eq()
+    }
+}
+
+impl PartialEq<&str> for ByteRope {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for ByteRope {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<ByteStr> for ByteRope {
+    fn eq(&self, other: &ByteStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<ByteRope> for str {
+    fn eq(&self, other: &ByteRope) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<ByteRope> for &str {
+    fn eq(&self, other: &ByteRope) -> bool {
+        other == *self
+    }
+}
+
+impl PartialEq<ByteRope> for ByteStr {
+    fn eq(&self, other: &ByteRope) -> bool {
+        other == self.as_str()
+    }
+}
@@ -0,0 +1,126 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::ByteStr;
+use crate::iter::{GraphemeIndices, Graphemes, Sentences, SplitWordBounds, UnicodeWords};
+
+// Segmentation follows Unicode Standard Annex #29, always using extended
+// grapheme clusters.
+impl ByteStr {
+    /// Returns an iterator over the extended grapheme clusters of the string,
+    /// as zero-copy `ByteStr` slices.
+    ///
+    /// A grapheme cluster is what a user perceives as a single character, such
+    /// as a letter with combining marks or an emoji made of several code points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("e\u{301}👨‍👩‍👧!");
+    /// let graphemes: Vec<_> = s.graphemes().collect();
+    /// assert_eq!(graphemes, ["e\u{301}", "👨‍👩‍👧", "!"]);
+    /// ```
+    #[must_use]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self, self.as_str().graphemes(true))
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of the string
+    /// and their byte offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("a\u{308}b");
+    /// let indices: Vec<_> = s.grapheme_indices().map(|(i, g)| (i, g.to_string())).collect();
+    /// assert_eq!(indices, [(0, "a\u{308}".to_string()), (3, "b".to_string())]);
+    /// ```
+    #[must_use]
+    pub fn grapheme_indices(&self) -> GraphemeIndices<'_> {
+        GraphemeIndices::new(self, self.as_str().grapheme_indices(true))
+    }
+
+    /// Returns an iterator over the words of the string, as zero-copy `ByteStr`
+    /// slices.
+    ///
+    /// Words are the substrings between word boundaries that contain at least
+    /// one alphanumeric character, so whitespace and punctuation are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("The quick (\"brown\") fox can't jump 32.3 feet, right?");
+    /// let words: Vec<_> = s.unicode_words().collect();
+    /// assert_eq!(words, ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]);
+    /// ```
+    #[must_use]
+    pub fn unicode_words(&self) -> UnicodeWords<'_> {
+        UnicodeWords::new(self, self.as_str().unicode_words())
+    }
+
+    /// Returns an iterator over the substrings of the string separated by word
+    /// boundaries, as zero-copy `ByteStr` slices.
+    ///
+    /// Unlike [`ByteStr::unicode_words`], whitespace and punctuation are kept, so
+    /// concatenating the substrings gives back the original string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Hello, world!");
+    /// let bounds: Vec<_> = s.split_word_bounds().collect();
+    /// assert_eq!(bounds, ["Hello", ",", " ", "world", "!"]);
+    /// ```
+    #[must_use]
+    pub fn split_word_bounds(&self) -> SplitWordBounds<'_> {
+        SplitWordBounds::new(self, self.as_str().split_word_bounds())
+    }
+
+    /// Returns an iterator over the sentences of the string, as zero-copy
+    /// `ByteStr` slices.
+    ///
+    /// Each sentence includes its trailing whitespace. Segments that contain no
+    /// alphanumeric character are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("Mr. Fox jumped. The dog was too lazy.");
+    /// let sentences: Vec<_> = s.sentences().collect();
+    /// assert_eq!(sentences, ["Mr. ", "Fox jumped. ", "The dog was too lazy."]);
+    /// ```
+    #[must_use]
+    pub fn sentences(&self) -> Sentences<'_> {
+        Sentences::new(self, self.as_str().unicode_sentences())
+    }
+
+    /// Shortens the string to its first `n` extended grapheme clusters.
+    ///
+    /// Unlike [`ByteStr::truncate`], this never splits a user-perceived
+    /// character, such as an emoji or a letter with combining marks. If the
+    /// string has `n` grapheme clusters or fewer, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let mut s = ByteStr::from("Cafe\u{301} 🇫🇷");
+    /// s.truncate_graphemes(4);
+    /// assert_eq!(s, "Cafe\u{301}");
+    /// ```
+    pub fn truncate_graphemes(&mut self, n: usize) {
+        if let Some((index, _)) = self.as_str().grapheme_indices(true).nth(n) {
+            self.truncate(index);
+        }
+    }
+}
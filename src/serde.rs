@@ -25,7 +25,7 @@ impl Visitor<'_> for ByteStrVisitor {
     where
         E: de::Error,
     {
-        Ok(Self::Value::from(v))
+        Ok(Self::Value::copy_from_str(v))
     }
 
     fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
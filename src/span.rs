@@ -0,0 +1,228 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+
+use crate::ByteStr;
+
+// A slice is located in its parent by address. Zero-copy methods such as
+// `split_once`, `lines` or `take_while` return views into the parent's buffer,
+// except for inline strings, which hold copies. An inline slice is located by
+// its offset in the inline string it was sliced from instead.
+impl ByteStr {
+    /// Returns the byte range of `parent` that `self` is a view of, or `None`
+    /// if `self` does not lie within `parent`.
+    ///
+    /// This finds where a slice returned by a zero-copy method came from, for
+    /// instance to report an error at the right place. Slices of strings short
+    /// enough to be stored inline are found as well, although they are copies.
+    /// An empty `ByteStr` is not found unless it was sliced from `parent`, and
+    /// neither is an equal string that was created separately from `parent`,
+    /// unless both are stored inline (see [`ByteStr::same_buffer`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let config = ByteStr::from("name = \"bytestr\"\nversion = \"0.3.1\"\n");
+    /// let (key, value) = config.lines().nth(1).unwrap().split_once(" = ").unwrap();
+    /// assert_eq!(key.offset_in(&config), Some(17..24));
+    /// assert_eq!(value.offset_in(&config), Some(27..34));
+    /// assert_eq!(ByteStr::from("version").offset_in(&config), None);
+    ///
+    /// let line = ByteStr::from("port = 8080");
+    /// let port = line.split_once(" = ").unwrap().1;
+    /// assert_eq!(port.offset_in(&line), Some(7..11));
+    /// ```
+    #[must_use]
+    pub fn offset_in(&self, parent: &Self) -> Option<Range<usize>> {
+        match (self.inline_root(), parent.inline_root()) {
+            (Some((root, start)), Some((parent_root, parent_start))) => {
+                let start = start
+                    .checked_sub(parent_start)
+                    .filter(|_| root == parent_root)?;
+                (start + self.len() <= parent.len()).then_some(start..start + self.len())
+            }
+            _ => subslice_range(parent, self),
+        }
+    }
+
+    /// Returns `true` if `self` is a view of part of `parent`.
+    ///
+    /// See [`ByteStr::offset_in`] for which slices are recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let request = ByteStr::from("GET /index.html HTTP/1.1");
+    /// let path = request.split(' ').nth(1).unwrap();
+    /// assert!(path.is_subslice_of(&request));
+    /// assert!(!request.is_subslice_of(&path));
+    /// ```
+    #[must_use]
+    pub fn is_subslice_of(&self, parent: &Self) -> bool {
+        self.offset_in(parent).is_some()
+    }
+
+    /// Returns `self` together with the byte range of `parent` it is a view
+    /// of, or `None` if it does not lie within `parent`.
+    ///
+    /// See [`ByteStr::offset_in`] for which slices are recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let source = ByteStr::from("let answer = 42;");
+    /// let number = source.split_whitespace().nth(3).unwrap().trim_end_matches(';');
+    /// let spanned = number.spanned_in(&source).unwrap();
+    /// assert_eq!(spanned.span(), 13..15);
+    /// assert_eq!(*spanned.get(), "42");
+    /// ```
+    #[must_use]
+    pub fn spanned_in(&self, parent: &Self) -> Option<Spanned<Self>> {
+        let span = self.offset_in(parent)?;
+        Some(Spanned::new(self.clone(), span))
+    }
+}
+
+/// Returns the byte range of `parent` that `subset` points into, if any.
+fn subslice_range(parent: &str, subset: &str) -> Option<Range<usize>> {
+    let parent_start = parent.as_ptr() as usize;
+    let start = (subset.as_ptr() as usize).checked_sub(parent_start)?;
+    (start <= parent.len() && subset.len() <= parent.len() - start)
+        .then_some(start..start + subset.len())
+}
+
+/// A value together with the byte range of the source it was taken from.
+///
+/// This carries the location of a token or a parsed value along with it, so
+/// that it can be reported later, for instance by diagnostics crates that take
+/// spans as `Range<usize>`. Comparison and hashing only look at the value and
+/// ignore the span, so that a `Spanned` key can be looked up by value.
+///
+/// A `Spanned<ByteStr>` is usually created with [`ByteStr::spanned_in`].
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::{ByteStr, Spanned};
+///
+/// let source = ByteStr::from("alpha beta alpha");
+/// let words: Vec<Spanned> = source
+///     .split(' ')
+///     .map(|word| word.spanned_in(&source).unwrap())
+///     .collect();
+///
+/// assert_eq!(words[2].span(), 11..16);
+/// assert_eq!(words[0], words[2]);
+/// assert_eq!(words[1], "beta");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Spanned<T = ByteStr> {
+    value: T,
+    span: Range<usize>,
+}
+
+impl<T> Spanned<T> {
+    /// Pairs `value` with the byte range `span`.
+    #[must_use]
+    pub const fn new(value: T, span: Range<usize>) -> Self {
+        Self { value, span }
+    }
+
+    /// Returns a reference to the value.
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the byte range the value was taken from.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Consumes the wrapper, returning the value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Consumes the wrapper, returning the value and its span.
+    #[must_use]
+    pub fn into_parts(self) -> (T, Range<usize>) {
+        (self.value, self.span)
+    }
+
+    /// Maps the value with `f`, keeping the span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let source = ByteStr::from("port = 8080");
+    /// let port = source.slice(7..).spanned_in(&source).unwrap();
+    /// let port = port.map(|s| s.parse::<u16>().unwrap());
+    /// assert_eq!((*port.get(), port.span()), (8080, 7..11));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Spanned<U> {
+        Spanned::new(f(self.value), self.span)
+    }
+}
+
+impl<T: AsRef<str>> AsRef<str> for Spanned<T> {
+    fn as_ref(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Spanned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Spanned<U>> for Spanned<T> {
+    fn eq(&self, other: &Spanned<U>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Spanned<T> {}
+
+impl<T: PartialOrd> PartialOrd for Spanned<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for Spanned<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for Spanned<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: PartialEq<str>> PartialEq<str> for Spanned<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<'a, T: PartialEq<&'a str>> PartialEq<&'a str> for Spanned<T> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.value == *other
+    }
+}
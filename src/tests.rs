@@ -1,4 +1,7 @@
-use crate::ByteStr;
+use crate::{
+    ByteRope, ByteStr, ByteStrBuf, ByteStrCursor, ByteStrMut, CaseInsensitive, CaseInsensitiveStr,
+    LineCol, LineIndex, SliceError, Spanned, Utf8Decoder,
+};
 use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
 
 #[test]
@@ -658,3 +661,2821 @@ fn test_from_utf16_consistency() {
         assert_eq!(bs_strict.as_str(), *test_str);
     }
 }
+
+// ByteStrMut related tests
+#[test]
+fn test_bytestr_mut_push_and_freeze() {
+    let mut buf = ByteStrMut::new();
+    buf.push_str("Hello");
+    buf.push(',');
+    buf.push(' ');
+    buf.push_str("世界");
+    buf.push('🦀');
+    assert_eq!(buf.len(), "Hello, 世界🦀".len());
+
+    let bs = buf.freeze();
+    assert_eq!(bs.as_str(), "Hello, 世界🦀");
+}
+
+#[test]
+fn test_bytestr_mut_freeze_is_zero_copy() {
+    let mut buf = ByteStrMut::with_capacity(32);
+    buf.push_str("no copy");
+    let ptr = buf.as_str().as_ptr();
+    let bs = buf.freeze();
+    assert_eq!(bs.as_str().as_ptr(), ptr);
+}
+
+#[test]
+fn test_bytestr_mut_from_string_reuses_allocation() {
+    let mut s = String::with_capacity(64);
+    s.push_str("reused");
+    let ptr = s.as_ptr();
+    let buf = ByteStrMut::from(s);
+    assert_eq!(buf.as_str().as_ptr(), ptr);
+    assert!(buf.capacity() >= 64);
+}
+
+#[test]
+fn test_bytestr_mut_fmt_write() {
+    use core::fmt::Write;
+
+    let mut buf = ByteStrMut::new();
+    let (n, word, ch) = (1, "two", '三');
+    write!(buf, "{n}-{word}-{ch}").unwrap();
+    assert_eq!(buf, "1-two-三");
+}
+
+#[test]
+fn test_bytestr_mut_reserve() {
+    let mut buf = ByteStrMut::new();
+    buf.reserve(100);
+    assert!(buf.capacity() >= 100);
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_bytestr_mut_split() {
+    let mut buf = ByteStrMut::from("key: 值");
+    let value = buf.split_off(5);
+    let key = buf.split_to(3);
+    assert_eq!(key, "key");
+    assert_eq!(buf, ": ");
+    assert_eq!(value, "值");
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn test_bytestr_mut_split_off_invalid_boundary() {
+    let mut buf = ByteStrMut::from("世界");
+    let _ = buf.split_off(1);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn test_bytestr_mut_split_to_invalid_boundary() {
+    let mut buf = ByteStrMut::from("世界");
+    let _ = buf.split_to(4);
+}
+
+#[test]
+fn test_bytestr_mut_truncate_and_clear() {
+    let mut buf = ByteStrMut::from("hello 世界");
+    buf.truncate(100);
+    assert_eq!(buf, "hello 世界");
+    buf.truncate(6);
+    assert_eq!(buf, "hello ");
+    buf.clear();
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_bytestr_mut_from_utf8() {
+    let buf = ByteStrMut::from_utf8(bytes::BytesMut::from("valid")).unwrap();
+    assert_eq!(buf, "valid");
+
+    let invalid = bytes::BytesMut::from(&[0x66, 0xff][..]);
+    assert!(ByteStrMut::from_utf8(invalid).is_err());
+}
+
+#[test]
+fn test_bytestr_mut_extend_and_collect() {
+    let mut buf: ByteStrMut = "abc".chars().collect();
+    buf.extend(["d", "e"]);
+    buf.extend(['f']);
+    assert_eq!(buf, "abcdef");
+    assert_eq!(ByteStr::from(buf), "abcdef");
+}
+
+// Utf8Decoder related tests
+#[test]
+fn test_decoder_single_chunk() {
+    let mut decoder = Utf8Decoder::new();
+    let chunk = bytes::Bytes::from("Hello, 世界!");
+    let ptr = chunk.as_ptr();
+    let output = decoder.decode(chunk).unwrap();
+    assert!(output.head().is_none());
+    assert_eq!(output.body(), "Hello, 世界!");
+    assert_eq!(output.body().as_str().as_ptr(), ptr);
+    assert_eq!(decoder.position(), 14);
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_decoder_every_split_point() {
+    let text = "a世🦀b界";
+    let bytes = text.as_bytes();
+
+    for split in 0..=bytes.len() {
+        let mut decoder = Utf8Decoder::new();
+        let mut out = String::new();
+        for piece in decoder.decode(&bytes[..split]).unwrap() {
+            out.push_str(&piece);
+        }
+        for piece in decoder.decode(&bytes[split..]).unwrap() {
+            out.push_str(&piece);
+        }
+        decoder.finish().unwrap();
+        assert_eq!(out, text, "split at {split}");
+        assert_eq!(decoder.position(), bytes.len() as u64);
+    }
+}
+
+#[test]
+fn test_decoder_byte_by_byte() {
+    let text = "🦀 crab 世界";
+    let mut decoder = Utf8Decoder::new();
+    let mut out = String::new();
+    for byte in text.bytes() {
+        let output = decoder.decode(vec![byte]).unwrap();
+        out.push_str(&output.into_byte_str());
+    }
+    decoder.finish().unwrap();
+    assert_eq!(out, text);
+}
+
+#[test]
+fn test_decoder_invalid_reports_absolute_offset() {
+    let mut decoder = Utf8Decoder::new();
+    decoder.decode(&b"0123456789"[..]).unwrap();
+
+    let err = decoder.decode(&[b'a', b'b', 0xFF, b'c'][..]).unwrap_err();
+    assert_eq!(err.offset(), 12);
+    assert_eq!(err.invalid_bytes(), &[0xFF]);
+    assert!(!err.is_incomplete());
+    assert_eq!(err.valid().body(), "ab");
+    assert_eq!(err.remaining().as_ref(), b"c");
+
+    // Decoding can be resumed with the remaining bytes
+    let resumed = decoder.decode(err.into_remaining()).unwrap();
+    assert_eq!(resumed.body(), "c");
+    assert_eq!(decoder.position(), 14);
+}
+
+#[test]
+fn test_decoder_invalid_after_carried_bytes() {
+    let mut decoder = Utf8Decoder::new();
+    // Start of a 3-byte sequence, then a byte that cannot continue it
+    decoder.decode(&[b'x', 0xE4][..]).unwrap();
+    assert_eq!(decoder.pending(), &[0xE4]);
+
+    let err = decoder.decode(&b"yz"[..]).unwrap_err();
+    assert_eq!(err.offset(), 1);
+    assert_eq!(err.invalid_bytes(), &[0xE4]);
+    assert_eq!(err.remaining().as_ref(), b"yz");
+    assert!(decoder.pending().is_empty());
+}
+
+#[test]
+fn test_decoder_incomplete_at_end() {
+    let mut decoder = Utf8Decoder::new();
+    decoder.decode(&"ab世".as_bytes()[..4]).unwrap();
+    let err = decoder.finish().unwrap_err();
+    assert!(err.is_incomplete());
+    assert_eq!(err.offset(), 2);
+    assert_eq!(err.invalid_bytes(), &"世".as_bytes()[..2]);
+
+    // The decoder is reusable after finishing
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_decoder_accepts_bytes_mut() {
+    let mut decoder = Utf8Decoder::new();
+    let chunk = bytes::BytesMut::from("mutable");
+    assert_eq!(decoder.decode(chunk).unwrap().body(), "mutable");
+}
+
+// Hash / Eq / Ord conformance with str, required by `Borrow<str>`
+const CONFORMANCE_CASES: [&str; 10] = [
+    "", "a", "ab", "abc", "b", "a\0", "\u{ff}", "世界", "世", "🦀",
+];
+
+fn std_hash<T: core::hash::Hash + ?Sized>(value: &T) -> u64 {
+    extern crate std;
+    use core::hash::BuildHasher;
+
+    std::hash::BuildHasherDefault::<std::hash::DefaultHasher>::default().hash_one(value)
+}
+
+#[test]
+fn test_hash_matches_str() {
+    for case in CONFORMANCE_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(std_hash(&bs), std_hash(case), "{case:?}");
+        assert_eq!(std_hash(&ByteStr::from_static(case)), std_hash(case));
+    }
+
+    // Composite keys must hash like their str counterparts, too
+    let pair = (ByteStr::from("a"), ByteStr::from("bc"));
+    assert_eq!(std_hash(&pair), std_hash(&("a", "bc")));
+    assert_ne!(
+        std_hash(&pair),
+        std_hash(&(ByteStr::from("ab"), ByteStr::from("c")))
+    );
+}
+
+#[test]
+fn test_hash_map_lookup_by_str() {
+    extern crate std;
+    use std::collections::{HashMap, HashSet};
+
+    let mut map = HashMap::new();
+    for (i, case) in CONFORMANCE_CASES.iter().enumerate() {
+        map.insert(ByteStr::from(*case), i);
+    }
+    for (i, case) in CONFORMANCE_CASES.iter().enumerate() {
+        assert_eq!(map.get(*case), Some(&i), "{case:?}");
+    }
+    assert_eq!(map.get("missing"), None);
+
+    let set: HashSet<ByteStr> = CONFORMANCE_CASES
+        .iter()
+        .map(|s| ByteStr::from(*s))
+        .collect();
+    assert!(CONFORMANCE_CASES.iter().all(|case| set.contains(*case)));
+}
+
+#[test]
+fn test_btree_map_lookup_by_str() {
+    use alloc::collections::BTreeMap;
+
+    let map: BTreeMap<ByteStr, usize> = CONFORMANCE_CASES
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (ByteStr::from(*s), i))
+        .collect();
+    for (i, case) in CONFORMANCE_CASES.iter().enumerate() {
+        assert_eq!(map.get(*case), Some(&i));
+    }
+
+    // Iteration order must match the order of the borrowed form
+    let mut sorted = CONFORMANCE_CASES;
+    sorted.sort_unstable();
+    assert!(map.keys().map(ByteStr::as_str).eq(sorted));
+}
+
+#[test]
+fn test_eq_and_ord_match_str() {
+    for a in CONFORMANCE_CASES {
+        for b in CONFORMANCE_CASES {
+            let (bs_a, bs_b) = (ByteStr::from(a), ByteStr::from(b));
+            assert_eq!(bs_a == bs_b, a == b, "{a:?} == {b:?}");
+            assert_eq!(bs_a.cmp(&bs_b), a.cmp(b), "{a:?} cmp {b:?}");
+            assert_eq!(bs_a.partial_cmp(&bs_b), a.partial_cmp(b));
+        }
+    }
+}
+
+#[test]
+fn test_cross_type_comparisons_match_str() {
+    for a in CONFORMANCE_CASES {
+        for b in CONFORMANCE_CASES {
+            let bs = ByteStr::from(a);
+            let expected_eq = a == b;
+            let expected_ord = a.partial_cmp(b);
+            let string = String::from(b);
+            let cow: Cow<'_, str> = Cow::Borrowed(b);
+
+            // ByteStr on the left
+            assert_eq!(bs == *b, expected_eq);
+            assert_eq!(bs == b, expected_eq);
+            assert_eq!(bs == string, expected_eq);
+            assert_eq!(bs == cow, expected_eq);
+            assert_eq!(bs.partial_cmp(b), expected_ord);
+            assert_eq!(bs.partial_cmp(&b), expected_ord);
+            assert_eq!(bs.partial_cmp(&string), expected_ord);
+            assert_eq!(bs.partial_cmp(&cow), expected_ord);
+
+            // ByteStr on the right
+            let bs = ByteStr::from(b);
+            let expected_eq = a == b;
+            let expected_ord = a.partial_cmp(b);
+            let string = String::from(a);
+            let cow: Cow<'_, str> = Cow::Owned(String::from(a));
+
+            assert_eq!(*a == bs, expected_eq);
+            assert_eq!(a == bs, expected_eq);
+            assert_eq!(string == bs, expected_eq);
+            assert_eq!(cow == bs, expected_eq);
+            assert_eq!(a.partial_cmp(&bs), expected_ord);
+            assert_eq!((*a).partial_cmp(&bs), expected_ord);
+            assert_eq!(string.partial_cmp(&bs), expected_ord);
+            assert_eq!(cow.partial_cmp(&bs), expected_ord);
+        }
+    }
+}
+
+#[test]
+fn test_borrow_agrees_with_eq_hash_ord() {
+    use core::borrow::Borrow;
+
+    for a in CONFORMANCE_CASES {
+        for b in CONFORMANCE_CASES {
+            let (bs_a, bs_b) = (ByteStr::from(a), ByteStr::from(b));
+            let (str_a, str_b): (&str, &str) = (bs_a.borrow(), bs_b.borrow());
+            assert_eq!(bs_a == bs_b, str_a == str_b);
+            assert_eq!(bs_a.cmp(&bs_b), str_a.cmp(str_b));
+            if bs_a == bs_b {
+                assert_eq!(std_hash(&bs_a), std_hash(str_b));
+            }
+        }
+    }
+}
+
+// FromUtf8Error related tests
+#[test]
+fn test_from_utf8_error_returns_original_bytes() {
+    let payload = bytes::Bytes::from(vec![b'a', b'b', 0xFF, b'c']);
+    let ptr = payload.as_ptr();
+    let err = ByteStr::from_utf8(payload).unwrap_err();
+
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(err.utf8_error().error_len(), Some(1));
+    assert_eq!(err.as_bytes().as_ref(), &[b'a', b'b', 0xFF, b'c']);
+
+    let bytes = err.into_bytes();
+    assert_eq!(bytes.as_ptr(), ptr);
+}
+
+#[test]
+fn test_from_utf8_error_valid_prefix_is_zero_copy() {
+    let err = ByteStr::from_utf8(b"h\xC3\xA9\xC0llo".to_vec()).unwrap_err();
+
+    let prefix = err.valid_prefix();
+    assert_eq!(prefix, "hé");
+    assert_eq!(prefix.as_str().as_ptr(), err.as_bytes().as_ptr());
+}
+
+#[test]
+fn test_from_utf8_error_display_and_source() {
+    use core::error::Error;
+
+    let err = ByteStr::from_utf8(vec![0xFF]).unwrap_err();
+    assert_eq!(format!("{err}"), format!("{}", err.utf8_error()));
+    assert!(err.source().is_some());
+}
+
+// Range slicing related tests
+#[test]
+fn test_slice_ranges() {
+    let bs = ByteStr::from("Hello, world!");
+    assert_eq!(bs.slice(..), "Hello, world!");
+    assert_eq!(bs.slice(0..5), "Hello");
+    assert_eq!(bs.slice(7..), "world!");
+    assert_eq!(bs.slice(..5), "Hello");
+    assert_eq!(bs.slice(..=4), "Hello");
+    assert_eq!(bs.slice(7..=11), "world");
+    assert_eq!(bs.slice(13..), "");
+}
+
+#[test]
+fn test_slice_is_zero_copy() {
+    let bs = ByteStr::from_utf8(b"Hello, world!".to_vec()).unwrap();
+    let slice = bs.slice(7..12);
+    assert_eq!(slice.as_str().as_ptr(), bs.as_str()[7..].as_ptr());
+}
+
+#[test]
+fn test_slice_excluded_start_bound() {
+    use core::ops::Bound;
+
+    let bs = ByteStr::from("abc");
+    assert_eq!(bs.slice((Bound::Excluded(0), Bound::Unbounded)), "bc");
+    assert!(
+        bs.get((Bound::Excluded(usize::MAX), Bound::Unbounded))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_get_and_try_slice_errors() {
+    let bs = ByteStr::from("Hello, 世界!");
+
+    assert_eq!(bs.get(7..10).unwrap(), "世");
+    assert!(bs.get(8..).is_none());
+    assert!(bs.get(..100).is_none());
+
+    assert_eq!(
+        bs.try_slice(8..),
+        Err(SliceError::NotCharBoundary { index: 8 })
+    );
+    assert_eq!(
+        bs.try_slice(..=8),
+        Err(SliceError::NotCharBoundary { index: 9 })
+    );
+    assert_eq!(
+        bs.try_slice(10..100),
+        Err(SliceError::OutOfBounds {
+            start: 10,
+            end: 100,
+            len: 14
+        })
+    );
+    let (start, end) = (5, 3);
+    assert_eq!(
+        bs.try_slice(start..end),
+        Err(SliceError::OutOfBounds {
+            start: 5,
+            end: 3,
+            len: 14
+        })
+    );
+    assert_eq!(
+        bs.try_slice(..=usize::MAX),
+        Err(SliceError::OutOfBounds {
+            start: 0,
+            end: usize::MAX,
+            len: 14
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "byte index 8 is not a char boundary")]
+fn test_slice_panic_on_invalid_boundary() {
+    let bs = ByteStr::from("Hello, 世界!");
+    let _ = bs.slice(8..);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_slice_panic_out_of_bounds() {
+    let bs = ByteStr::from("Hello");
+    let _ = bs.slice(..6);
+}
+
+#[test]
+fn test_index_range_inclusive() {
+    let bs = ByteStr::from("Hello, 世界!");
+    assert_eq!(&bs[0..=4], "Hello");
+    assert_eq!(&bs[7..=9], "世");
+}
+
+// Pattern related tests
+const SPLIT_CASES: [&str; 8] = ["", "a", "a,b", ",a,,b,", "ab", "世,界", ",,", "🦀a🦀"];
+
+fn as_strs(parts: impl Iterator<Item = ByteStr>) -> Vec<String> {
+    parts.map(|s| String::from(s.as_str())).collect()
+}
+
+#[test]
+fn test_split_matches_std_for_all_pattern_kinds() {
+    for case in SPLIT_CASES {
+        let bs = ByteStr::from(case);
+        let comma = String::from(",");
+        let owned_str: &str = &comma;
+
+        assert_eq!(as_strs(bs.split(',')), case.split(',').collect::<Vec<_>>());
+        assert_eq!(as_strs(bs.split(",")), case.split(',').collect::<Vec<_>>());
+        assert_eq!(
+            as_strs(bs.split(&comma)),
+            case.split(&comma).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split(&owned_str)),
+            case.split(&owned_str).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split([',', 'a'])),
+            case.split([',', 'a']).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split(&[',', 'a'])),
+            case.split(&[',', 'a']).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split(&[',', 'a'][..])),
+            case.split(&[',', 'a'][..]).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split(|c: char| !c.is_ascii())),
+            case.split(|c: char| !c.is_ascii()).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_split_empty_pattern_matches_std() {
+    for case in SPLIT_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.split("")),
+            case.split("").collect::<Vec<_>>(),
+            "{case:?}"
+        );
+        for n in 0..5 {
+            assert_eq!(
+                as_strs(bs.splitn(n, "")),
+                case.splitn(n, "").collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_splitn_matches_std() {
+    for case in SPLIT_CASES {
+        let bs = ByteStr::from(case);
+        for n in 0..5 {
+            assert_eq!(
+                as_strs(bs.splitn(n, ',')),
+                case.splitn(n, ',').collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(bs.splitn(n, ",")),
+                case.splitn(n, ',').collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_split_overlapping_str_pattern() {
+    let bs = ByteStr::from("aaaaa");
+    assert_eq!(
+        as_strs(bs.split("aa")),
+        "aaaaa".split("aa").collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_split_once_with_patterns() {
+    let bs = ByteStr::from("key: 值=1");
+    let (key, value) = bs.split_once(':').unwrap();
+    assert_eq!(key, "key");
+    assert_eq!(value, " 值=1");
+
+    let (before, after) = bs.split_once(['=', ':']).unwrap();
+    assert_eq!((before.as_str(), after.as_str()), ("key", " 值=1"));
+
+    let (before, after) = bs.split_once(|c: char| !c.is_ascii()).unwrap();
+    assert_eq!((before.as_str(), after.as_str()), ("key: ", "=1"));
+
+    assert!(bs.split_once('#').is_none());
+}
+
+#[test]
+fn test_strip_prefix_suffix_with_patterns() {
+    let bs = ByteStr::from("__name__");
+    assert_eq!(bs.strip_prefix('_').unwrap(), "_name__");
+    assert_eq!(bs.strip_suffix(|c: char| c == '_').unwrap(), "__name_");
+    assert_eq!(bs.strip_prefix(&String::from("__")).unwrap(), "name__");
+    assert!(bs.strip_prefix(['a', 'b']).is_none());
+}
+
+#[test]
+fn test_take_until_with_patterns() {
+    let bs = ByteStr::from("ident(args)");
+    assert_eq!(bs.take_until('('), "ident");
+    assert_eq!(bs.take_until(|c: char| !c.is_alphanumeric()), "ident");
+    assert_eq!(bs.take_until(['[', '{']), "ident(args)");
+}
+
+#[test]
+fn test_pattern_split_is_zero_copy() {
+    let bs = ByteStr::from_utf8(b"a;b;c".to_vec()).unwrap();
+    let ptr = bs.as_str().as_ptr();
+    let parts: Vec<_> = bs.split(';').collect();
+    assert_eq!(parts[2].as_str().as_ptr(), ptr.wrapping_add(4));
+}
+
+// Split family related tests
+const SPLIT_FAMILY_CASES: [&str; 10] = [
+    "",
+    ",",
+    "a",
+    "a,b",
+    ",a,,b,",
+    "a,b,",
+    "世,界,",
+    ",,",
+    "🦀a🦀",
+    "aaaa",
+];
+
+/// Drains both iterators alternating between the front and the back.
+fn alternate<I, J, T>(mut ours: I, mut theirs: J)
+where
+    I: DoubleEndedIterator<Item = ByteStr>,
+    J: DoubleEndedIterator<Item = T>,
+    T: AsRef<str> + core::fmt::Debug,
+{
+    let mut front = true;
+    loop {
+        let (a, b) = if front {
+            (ours.next(), theirs.next())
+        } else {
+            (ours.next_back(), theirs.next_back())
+        };
+        assert_eq!(a.as_deref(), b.as_ref().map(AsRef::as_ref));
+        if a.is_none() {
+            break;
+        }
+        front = !front;
+    }
+}
+
+#[test]
+fn test_rsplit_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.rsplit(',')),
+            case.rsplit(',').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.rsplit("aa")),
+            case.rsplit("aa").collect::<Vec<_>>()
+        );
+        assert_eq!(as_strs(bs.rsplit("")), case.rsplit("").collect::<Vec<_>>());
+        assert_eq!(
+            as_strs(bs.split(',').rev()),
+            case.split(',').rev().collect::<Vec<_>>()
+        );
+        alternate(bs.split(','), case.split(','));
+        alternate(bs.rsplit(','), case.rsplit(','));
+        alternate(
+            bs.split(char::is_alphabetic),
+            case.split(char::is_alphabetic),
+        );
+    }
+}
+
+#[test]
+fn test_rsplitn_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        for n in 0..5 {
+            assert_eq!(
+                as_strs(bs.rsplitn(n, ',')),
+                case.rsplitn(n, ',').collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(bs.rsplitn(n, "aa")),
+                case.rsplitn(n, "aa").collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rsplit_once_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        let ours = bs.rsplit_once(',');
+        let theirs = case.rsplit_once(',');
+        assert_eq!(ours.as_ref().map(|(a, b)| (a.as_str(), b.as_str())), theirs);
+    }
+}
+
+#[test]
+fn test_split_terminator_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.split_terminator(',')),
+            case.split_terminator(',').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.rsplit_terminator(',')),
+            case.rsplit_terminator(',').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split_terminator("")),
+            case.split_terminator("").collect::<Vec<_>>()
+        );
+        alternate(bs.split_terminator(','), case.split_terminator(','));
+        alternate(bs.rsplit_terminator(','), case.rsplit_terminator(','));
+    }
+}
+
+#[test]
+fn test_split_inclusive_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.split_inclusive(',')),
+            case.split_inclusive(',').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split_inclusive("aa")),
+            case.split_inclusive("aa").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.split_inclusive(',').rev()),
+            case.split_inclusive(',').rev().collect::<Vec<_>>()
+        );
+        alternate(bs.split_inclusive(','), case.split_inclusive(','));
+    }
+}
+
+#[test]
+fn test_split_ascii_whitespace_matches_std() {
+    for case in ["", " ", "a b", "  a\t\nb  ", "a\u{A0}b c", "\u{3000}x y"] {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.split_ascii_whitespace()),
+            case.split_ascii_whitespace().collect::<Vec<_>>()
+        );
+        alternate(bs.split_ascii_whitespace(), case.split_ascii_whitespace());
+    }
+}
+
+#[test]
+fn test_matches_family_matches_std() {
+    for case in SPLIT_FAMILY_CASES {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.matches("aa")),
+            case.matches("aa").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.rmatches("aa")),
+            case.rmatches("aa").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            as_strs(bs.matches("")),
+            case.matches("").collect::<Vec<_>>()
+        );
+        alternate(bs.matches(','), case.matches(','));
+        alternate(bs.rmatches(['a', ',']), case.rmatches(['a', ',']));
+
+        let ours: Vec<_> = bs
+            .match_indices(',')
+            .map(|(i, s)| (i, String::from(s.as_str())))
+            .collect();
+        let theirs: Vec<_> = case
+            .match_indices(',')
+            .map(|(i, s)| (i, String::from(s)))
+            .collect();
+        assert_eq!(ours, theirs);
+
+        let ours: Vec<_> = bs.rmatch_indices("").map(|(i, _)| i).collect();
+        let theirs: Vec<_> = case.rmatch_indices("").map(|(i, _)| i).collect();
+        assert_eq!(ours, theirs);
+
+        let ours: Vec<_> = bs.match_indices(',').rev().map(|(i, _)| i).collect();
+        let theirs: Vec<_> = case.match_indices(',').rev().map(|(i, _)| i).collect();
+        assert_eq!(ours, theirs);
+    }
+}
+
+#[test]
+fn test_split_family_is_zero_copy() {
+    let bs = ByteStr::from_utf8(b"x=1;y=2".to_vec()).unwrap();
+    let base = bs.as_str().as_ptr();
+    let last = bs.rsplit(';').next().unwrap();
+    assert_eq!(last.as_str().as_ptr(), base.wrapping_add(4));
+    let (_, idx) = bs.match_indices('=').last().map(|(i, m)| (m, i)).unwrap();
+    assert_eq!(idx, 5);
+}
+
+// Owning iterator related tests
+struct LineSource {
+    lines: crate::iter::Lines,
+}
+
+fn fields(record: &str) -> crate::iter::Split<char> {
+    ByteStr::from(record).into_split(',')
+}
+
+#[test]
+fn test_owning_iterators_outlive_source() {
+    let mut source = LineSource {
+        lines: ByteStr::from("first\r\nsecond\n").into_lines(),
+    };
+    assert_eq!(source.lines.next().unwrap(), "first");
+    assert_eq!(source.lines.next().unwrap(), "second");
+    assert!(source.lines.next().is_none());
+
+    assert_eq!(as_strs(fields("a,b,,c")), ["a", "b", "", "c"]);
+}
+
+#[test]
+fn test_owning_iterators_clone_for_backtracking() {
+    let mut words = ByteStr::from("alpha beta gamma").into_split_whitespace();
+    assert_eq!(words.next().unwrap(), "alpha");
+    let checkpoint = words.clone();
+    assert_eq!(words.next().unwrap(), "beta");
+    words = checkpoint;
+    assert_eq!(as_strs(words), ["beta", "gamma"]);
+}
+
+#[test]
+fn test_lines_matches_std() {
+    for case in [
+        "",
+        "\n",
+        "a",
+        "a\n",
+        "a\r\nb",
+        "a\n\nb\r\n",
+        "\r\n\r\n",
+        "a\rb\n",
+        "世\n界",
+    ] {
+        let bs = ByteStr::from(case);
+        assert_eq!(as_strs(bs.lines()), case.lines().collect::<Vec<_>>());
+        assert_eq!(
+            as_strs(bs.lines().rev()),
+            case.lines().rev().collect::<Vec<_>>()
+        );
+        alternate(bs.lines(), case.lines());
+    }
+}
+
+#[test]
+fn test_split_whitespace_matches_std() {
+    for case in ["", " ", "a b", "  a\t\nb  ", "a\u{A0}b c", "\u{3000}x y"] {
+        let bs = ByteStr::from(case);
+        assert_eq!(
+            as_strs(bs.split_whitespace()),
+            case.split_whitespace().collect::<Vec<_>>()
+        );
+        alternate(bs.split_whitespace(), case.split_whitespace());
+    }
+}
+
+#[test]
+fn test_owning_iterators_are_fused() {
+    let mut parts = ByteStr::from("a,b").into_split(',');
+    assert_eq!(parts.by_ref().count(), 2);
+    assert!(parts.next().is_none());
+    assert!(parts.next_back().is_none());
+
+    let mut lines = ByteStr::from("x").into_lines();
+    assert_eq!(lines.next().unwrap(), "x");
+    assert!(lines.next().is_none());
+    assert!(lines.next().is_none());
+}
+
+// Cursor related tests
+#[test]
+fn test_cursor_tokens_are_zero_copy() {
+    let input = ByteStr::from_utf8(b"name: value".to_vec()).unwrap();
+    let base = input.as_str().as_ptr();
+    let mut cursor = ByteStrCursor::new(input);
+
+    let key = cursor.take_until(':');
+    cursor.expect(": ").unwrap();
+    let value = cursor.take_rest();
+
+    assert_eq!(key, "name");
+    assert_eq!(value, "value");
+    assert_eq!(key.as_str().as_ptr(), base);
+    assert_eq!(value.as_str().as_ptr(), base.wrapping_add(6));
+    assert!(cursor.is_empty());
+    assert!(cursor.peek().is_none());
+}
+
+#[test]
+fn test_cursor_peek_and_advance() {
+    let mut cursor = ByteStrCursor::new(ByteStr::from("é!"));
+    assert_eq!(cursor.peek(), Some(0xC3));
+    assert_eq!(cursor.peek_char(), Some('é'));
+    assert_eq!(cursor.next_char(), Some('é'));
+    assert_eq!(cursor.offset(), 2);
+    assert_eq!(cursor.advance(1), "!");
+    assert_eq!(cursor.next_char(), None);
+    assert_eq!(cursor.advance(0), "");
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary")]
+fn test_cursor_advance_inside_char_panics() {
+    ByteStrCursor::new(ByteStr::from("é")).advance(1);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_cursor_advance_past_end_panics() {
+    ByteStrCursor::new(ByteStr::from("ab")).advance(3);
+}
+
+#[test]
+fn test_cursor_eat_and_expect_leave_input_on_mismatch() {
+    let mut cursor = ByteStrCursor::new(ByteStr::from("abc"));
+    assert!(cursor.eat("abd").is_none());
+    assert_eq!(cursor.offset(), 0);
+
+    let err = cursor.expect('x').unwrap_err();
+    assert_eq!(err.found(), Some('a'));
+    assert_eq!(err.location().offset(), 0);
+    assert_eq!(cursor.offset(), 0);
+
+    assert_eq!(cursor.eat(|c: char| c == 'a').unwrap(), "a");
+    assert_eq!(cursor.eat("").unwrap(), "");
+    cursor.take_rest();
+    let err = cursor.expect('x').unwrap_err();
+    assert_eq!(err.found(), None);
+    assert_eq!(format!("{err}"), "unexpected end of input at 1:4");
+}
+
+#[test]
+fn test_cursor_take_until_without_match_consumes_rest() {
+    let mut cursor = ByteStrCursor::new(ByteStr::from("abc"));
+    assert_eq!(cursor.take_until(';'), "abc");
+    assert!(cursor.is_empty());
+    assert_eq!(cursor.take_until(';'), "");
+}
+
+#[test]
+fn test_cursor_line_and_column() {
+    let mut cursor = ByteStrCursor::new(ByteStr::from("ab\n\n世界x\r\ny"));
+    let mut seen = Vec::new();
+    while cursor.peek_char().is_some() {
+        let location = cursor.location();
+        seen.push((location.line(), location.column()));
+        cursor.next_char();
+    }
+    assert_eq!(
+        seen,
+        [
+            (1, 1),
+            (1, 2),
+            (1, 3),
+            (2, 1),
+            (3, 1),
+            (3, 2),
+            (3, 3),
+            (3, 4),
+            (3, 5),
+            (4, 1)
+        ]
+    );
+
+    let mut cursor = ByteStrCursor::new(ByteStr::from("a\nb\nc"));
+    cursor.take_until('c');
+    assert_eq!(cursor.location().line(), 3);
+    assert_eq!(cursor.location().column(), 1);
+}
+
+#[test]
+fn test_cursor_checkpoint_and_rewind() {
+    let mut cursor = ByteStrCursor::new(ByteStr::from("one\ntwo"));
+    cursor.take_until('\n');
+    let checkpoint = cursor.checkpoint();
+    assert_eq!(checkpoint.offset(), 3);
+
+    cursor.take_rest();
+    assert_eq!(cursor.location().line(), 2);
+    cursor.rewind(checkpoint);
+    assert_eq!(cursor.offset(), 3);
+    assert_eq!(cursor.location().line(), 1);
+    assert_eq!(cursor.location().column(), 4);
+    assert_eq!(cursor.remaining(), "\ntwo");
+
+    let fork = cursor.clone();
+    cursor.take_rest();
+    assert_eq!(fork.as_str(), "\ntwo");
+}
+
+#[test]
+#[should_panic(expected = "checkpoint does not belong to this cursor")]
+fn test_cursor_rewind_foreign_checkpoint_panics() {
+    let mut long = ByteStrCursor::new(ByteStr::from("long input"));
+    long.take_rest();
+    let checkpoint = long.checkpoint();
+    ByteStrCursor::new(ByteStr::from("short")).rewind(checkpoint);
+}
+
+// Non-panicking slicing related tests
+#[test]
+fn test_checked_take_skip_split_at_match_std() {
+    let s = ByteStr::from("aé世🦀");
+    for n in 0..=s.len() + 1 {
+        let std = s.as_str().split_at_checked(n);
+        assert_eq!(s.checked_take(n).as_deref(), std.map(|(l, _)| l));
+        assert_eq!(s.checked_skip(n).as_deref(), std.map(|(_, r)| r));
+        let ours = s.split_at_checked(n);
+        assert_eq!(ours.as_ref().map(|(l, r)| (l.as_str(), r.as_str())), std);
+    }
+    assert!(s.checked_take(usize::MAX).is_none());
+    assert!(s.checked_skip(usize::MAX).is_none());
+}
+
+#[test]
+fn test_try_truncate() {
+    let mut s = ByteStr::from("aé");
+    assert_eq!(s.try_truncate(10), Ok(()));
+    assert_eq!(s, "aé");
+    assert_eq!(
+        s.try_truncate(2),
+        Err(SliceError::NotCharBoundary { index: 2 })
+    );
+    assert_eq!(s, "aé");
+    assert_eq!(s.try_truncate(1), Ok(()));
+    assert_eq!(s, "a");
+}
+
+#[test]
+fn test_char_boundary_rounding() {
+    let s = ByteStr::from("aé世🦀");
+    let boundaries: Vec<usize> = s
+        .as_str()
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([s.len()])
+        .collect();
+    for index in 0..=s.len() + 2 {
+        let floor = s.floor_char_boundary(index);
+        let ceil = s.ceil_char_boundary(index);
+        let expected_floor = boundaries.iter().rev().find(|&&b| b <= index).copied();
+        let expected_ceil = boundaries.iter().find(|&&b| b >= index).copied();
+        assert_eq!(floor, expected_floor.unwrap());
+        assert_eq!(ceil, expected_ceil.unwrap_or(s.len()));
+    }
+}
+
+#[test]
+fn test_truncate_floor_is_zero_copy() {
+    let original = ByteStr::from_utf8("aé世🦀".as_bytes().to_vec()).unwrap();
+    let base = original.as_str().as_ptr();
+    for len in 0..=original.len() + 1 {
+        let mut s = original.clone();
+        s.truncate_floor(len);
+        assert_eq!(s.as_str(), &original[..original.floor_char_boundary(len)]);
+        assert_eq!(s.as_str().as_ptr(), base);
+    }
+}
+
+// Ownership recovery related tests
+#[test]
+fn test_into_string_reuses_unique_allocation() {
+    let s = ByteStr::from_utf8(String::from("Hello, world!").into_bytes()).unwrap();
+    let ptr = s.as_str().as_ptr();
+    let owned = s.into_string();
+    assert_eq!(owned, "Hello, world!");
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let s = ByteStr::from_utf8(String::from("shared").into_bytes()).unwrap();
+    let other = s.clone();
+    let owned = s.into_string();
+    assert_eq!(owned, "shared");
+    assert_ne!(owned.as_ptr(), other.as_str().as_ptr());
+
+    assert_eq!(ByteStr::from_static("static").into_string(), "static");
+}
+
+#[test]
+fn test_try_into_mut_requires_sole_ownership() {
+    let s = ByteStr::from_utf8(String::from("abc").into_bytes()).unwrap();
+    let ptr = s.as_str().as_ptr();
+    let buf = s.try_into_mut().unwrap();
+    assert_eq!(buf, "abc");
+    assert_eq!(buf.as_str().as_ptr(), ptr);
+
+    let s = ByteStr::from_utf8(String::from("abc").into_bytes()).unwrap();
+    let slice = s.slice(1..);
+    let s = s.try_into_mut().unwrap_err();
+    assert_eq!(s, "abc");
+    drop(slice);
+    assert!(s.try_into_mut().is_ok());
+
+    assert!(ByteStr::from_static("abc").try_into_mut().is_err());
+    assert!(ByteStr::new().try_into_mut().is_err());
+}
+
+#[test]
+fn test_make_mut_copies_only_when_shared() {
+    let mut s = ByteStr::from_utf8(String::from("abc").into_bytes()).unwrap();
+    let ptr = s.as_str().as_ptr();
+    s.make_mut(|buf| buf.truncate(2));
+    assert_eq!(s, "ab");
+    assert_eq!(s.as_str().as_ptr(), ptr);
+
+    let shared = s.clone();
+    let len = s.make_mut(|buf| {
+        buf.push('c');
+        buf.len()
+    });
+    assert_eq!(len, 3);
+    assert_eq!(s, "abc");
+    assert_eq!(shared, "ab");
+
+    let mut s = ByteStr::from_static("static");
+    s.make_mut(|buf| buf.push_str(" no more"));
+    assert_eq!(s, "static no more");
+}
+
+// Memory retention related tests
+#[test]
+fn test_capacity_tracks_backing_buffer() {
+    let mut owned = String::with_capacity(64);
+    owned.push_str("abc");
+    let s = ByteStrMut::from(owned).freeze();
+    assert_eq!(s.capacity(), 64);
+    assert_eq!(s.slice(1..).capacity(), 64);
+    assert_eq!(s.split(',').next().unwrap().capacity(), 64);
+
+    let mut buf = ByteStrMut::with_capacity(32);
+    buf.push_str("abc");
+    assert_eq!(buf.freeze().capacity(), 32);
+
+    let s = ByteStr::from_utf8(bytes::Bytes::from_static(b"abc")).unwrap();
+    assert_eq!(s.capacity(), 3);
+    assert!(!s.is_static());
+}
+
+#[test]
+fn test_static_strings_stay_static() {
+    let mut s = ByteStr::from_static("hello world");
+    assert!(s.is_static());
+    assert!(!s.is_unique());
+    assert!(s.take_until(' ').is_static());
+    s.truncate(5);
+    assert!(s.is_static());
+    s.compact();
+    assert!(s.is_static());
+    assert!(s.detach().is_static());
+    assert!(ByteStr::new().is_static());
+    assert!(ByteStr::default().is_static());
+
+    unsafe {
+        *s.as_bytes_mut() = bytes::Bytes::from(String::from("heap"));
+    }
+    assert!(!s.is_static());
+    assert_eq!(s, "heap");
+}
+
+#[test]
+fn test_compact_releases_parent_buffer() {
+    let parent = ByteStr::from("x".repeat(1024));
+    let mut slice = parent.slice(..4);
+    assert!(!parent.is_unique());
+
+    slice.compact();
+    assert_eq!(slice, "xxxx");
+    assert_eq!(slice.capacity(), 4);
+    assert!(slice.is_unique());
+    assert!(parent.is_unique());
+
+    let ptr = slice.as_str().as_ptr();
+    slice.compact();
+    assert_eq!(slice.as_str().as_ptr(), ptr);
+}
+
+#[test]
+fn test_slice_ref_compacting_threshold() {
+    let s = ByteStr::from_utf8(b"0123456789".to_vec()).unwrap();
+    let base = s.as_str().as_ptr();
+
+    let small = s.slice_ref_compacting(&s[..2], 0.5);
+    assert_ne!(small.as_str().as_ptr(), base);
+    assert_eq!(small.capacity(), 2);
+
+    let large = s.slice_ref_compacting(&s[..5], 0.5);
+    assert_eq!(large.as_str().as_ptr(), base);
+
+    let never = s.slice_ref_compacting(&s[..1], 0.0);
+    assert_eq!(never.as_str().as_ptr(), base);
+
+    let always = s.slice_ref_compacting(&s[..], 1.5);
+    assert_ne!(always.as_str().as_ptr(), base);
+    assert_eq!(always, s);
+}
+
+// Inline storage related tests
+fn is_inline(s: &ByteStr) -> bool {
+    matches!(s.0, crate::Repr::Inline { .. })
+}
+
+#[test]
+fn test_inline_does_not_grow_byte_str() {
+    assert_eq!(core::mem::size_of::<ByteStr>(), 40);
+}
+
+#[test]
+fn test_short_strings_are_inline() {
+    let max = "x".repeat(ByteStr::INLINE_CAPACITY);
+    let over = "x".repeat(ByteStr::INLINE_CAPACITY + 1);
+
+    assert!(is_inline(&ByteStr::from("")));
+    assert!(is_inline(&ByteStr::from(max.as_str())));
+    assert!(is_inline(&ByteStr::from(max)));
+    assert!(is_inline(&ByteStr::from('世')));
+    assert!(is_inline(&ByteStr::from(Cow::Borrowed("cow"))));
+    assert!(!is_inline(&ByteStr::from(over.as_str())));
+    assert!(!is_inline(&ByteStr::from(over)));
+
+    // Buffers that are already shared keep their zero-copy representation.
+    assert!(ByteStr::from_static("static").is_static());
+    assert!(!is_inline(&ByteStr::from_utf8(b"bytes".to_vec()).unwrap()));
+    assert!(!is_inline(&ByteStrMut::from("mut").freeze()));
+}
+
+#[test]
+fn test_copy_from_str() {
+    let max = "x".repeat(ByteStr::INLINE_CAPACITY);
+    let over = "x".repeat(ByteStr::INLINE_CAPACITY + 1);
+
+    assert!(is_inline(&ByteStr::copy_from_str("")));
+    assert!(is_inline(&ByteStr::copy_from_str(&max)));
+    let copied = ByteStr::copy_from_str(&over);
+    assert!(!is_inline(&copied));
+    assert_eq!(copied, over);
+    assert_eq!(copied.capacity(), over.len());
+    assert_ne!(copied.as_ptr(), over.as_ptr());
+    assert_eq!("short".parse::<ByteStr>().map(|s| is_inline(&s)), Ok(true));
+}
+
+#[test]
+fn test_inline_strings_behave_like_str() {
+    let s = ByteStr::from("héllo, wörld");
+    assert!(is_inline(&s));
+    let clone = s.clone();
+    assert!(clone.is_unique());
+    assert_eq!(clone, s);
+    assert_eq!(s.as_bytes(), "héllo, wörld".as_bytes());
+    assert_eq!(s.len(), 14);
+    assert_eq!(s.capacity(), 14);
+
+    let world = s.slice_ref(&s[8..]);
+    assert!(is_inline(&world));
+    assert_eq!(world, "wörld");
+    assert_eq!(s.slice(..6), "héllo");
+    assert_eq!(as_strs(s.split(", ")), ["héllo", "wörld"]);
+    assert_eq!(s.take_until(','), "héllo");
+
+    let mut truncated = s.clone();
+    truncated.truncate(3);
+    assert_eq!(truncated, "hé");
+    assert_eq!(s, "héllo, wörld");
+
+    assert_eq!(s.clone().into_bytes().as_ref(), s.as_bytes());
+}
+
+#[test]
+#[should_panic(expected = "subset is out of bounds")]
+fn test_inline_slice_ref_rejects_foreign_subset() {
+    let s = ByteStr::from("short");
+    let other = String::from("short");
+    let _ = s.slice_ref(&other[1..]);
+}
+
+#[test]
+#[should_panic(expected = "subset is out of bounds")]
+fn test_inline_slice_ref_rejects_longer_subset() {
+    let s = ByteStr::from("short");
+    let _ = s.slice_ref("longer than short");
+}
+
+#[test]
+#[should_panic(expected = "subset is out of bounds")]
+fn test_slice_ref_rejects_foreign_subset() {
+    let s = ByteStr::from_static("shared");
+    let other = String::from("shared");
+    let _ = s.slice_ref(&other[1..]);
+}
+
+#[test]
+fn test_inline_slices_and_clones_are_copies() {
+    let bs = ByteStr::from("Hello, world!");
+    let slice = bs.slice(7..12);
+    assert!(is_inline(&slice));
+    assert_eq!(slice, "world");
+    assert_ne!(slice.as_str().as_ptr(), bs.as_str()[7..].as_ptr());
+
+    let bs = ByteStr::from("a;b;c");
+    let parts: Vec<_> = bs.split(';').collect();
+    assert!(parts.iter().all(is_inline));
+    assert_ne!(parts[2].as_str().as_ptr(), bs.as_str()[4..].as_ptr());
+
+    let mut cursor = ByteStrCursor::new(ByteStr::from("name: value"));
+    let key = cursor.take_until(':');
+    assert!(is_inline(&key));
+    assert_eq!(key, "name");
+
+    let original = ByteStr::from("aé世🦀");
+    let mut s = original.clone();
+    assert_ne!(s.as_str().as_ptr(), original.as_str().as_ptr());
+    s.truncate_floor(5);
+    assert_eq!(s, "aé");
+}
+
+#[test]
+fn test_inline_strings_own_no_buffer() {
+    let mut owned = String::with_capacity(64);
+    owned.push_str("abc");
+    let s = ByteStr::from(owned);
+    assert!(is_inline(&s));
+    assert_eq!(s.capacity(), 3);
+
+    // Clones and slices are copies, so an inline string is never shared.
+    let slice = s.slice(1..);
+    assert!(s.is_unique());
+    assert_eq!(s.clone().try_into_mut().unwrap(), "abc");
+    assert_eq!(s.into_string(), "abc");
+    assert_eq!(slice, "bc");
+
+    let mut s = ByteStr::from(String::from("abc"));
+    let shared = s.clone();
+    s.make_mut(|buf| buf.truncate(2));
+    assert_eq!(s, "ab");
+    assert_eq!(shared, "abc");
+
+    let s = ByteStr::from("0123456789");
+    let small = s.slice_ref_compacting(&s[..2], 0.5);
+    assert_eq!(small.capacity(), 2);
+    assert!(is_inline(&small));
+}
+
+#[test]
+fn test_as_bytes_mut_spills_inline_string() {
+    let mut s = ByteStr::from("inline");
+    unsafe {
+        s.as_bytes_mut().truncate(2);
+    }
+    assert!(!is_inline(&s));
+    assert_eq!(s, "in");
+}
+
+// Interner related tests
+#[test]
+fn test_interner_deduplicates_content() {
+    let mut interner = crate::ByteStrInterner::new();
+    let a = interner.intern("content-length");
+    let b = interner.intern(&ByteStr::from("content-length"));
+    let c = interner.intern("x");
+
+    assert!(ByteStr::ptr_eq(&a, &b));
+    assert!(!ByteStr::ptr_eq(&a, &c));
+    assert!(!a.is_static());
+    assert!(ByteStr::ptr_eq(&interner.get("x").unwrap(), &c));
+    assert!(interner.get("missing").is_none());
+    assert_eq!(interner.len(), 2);
+
+    let stats = interner.stats();
+    assert_eq!(stats.entries, 2);
+    assert_eq!(stats.bytes, 15);
+    assert_eq!((stats.hits, stats.misses), (1, 2));
+}
+
+#[test]
+fn test_interner_static_seeds() {
+    let mut interner = crate::ByteStrInterner::new();
+    let seeded = interner.intern_static("host");
+    assert!(seeded.is_static());
+    assert!(ByteStr::ptr_eq(&interner.intern("host"), &seeded));
+    assert!(ByteStr::ptr_eq(&interner.intern_static("host"), &seeded));
+    assert_eq!(interner.stats().bytes, 0);
+
+    drop(seeded);
+    assert_eq!(interner.evict_unused(), 0);
+    assert!(interner.contains("host"));
+}
+
+#[test]
+fn test_interner_eviction_and_limit() {
+    let mut interner = crate::ByteStrInterner::with_max_bytes(6);
+    let abc = interner.intern("abc");
+    interner.intern("def");
+    assert_eq!(interner.stats().bytes, 6);
+
+    let ghi = interner.intern("ghi");
+    assert!(interner.contains("ghi"));
+    assert!(!interner.contains("def"));
+    assert_eq!(interner.stats().bytes, 6);
+
+    let too_long = interner.intern("jklmnop");
+    assert_eq!(too_long, "jklmnop");
+    assert!(!interner.contains("jklmnop"));
+    assert_eq!(interner.len(), 2);
+
+    drop((abc, ghi));
+    interner.clear();
+    assert!(interner.is_empty());
+    assert_eq!(interner.stats().bytes, 0);
+}
+
+#[cfg(any(feature = "std", feature = "spin"))]
+#[test]
+fn test_sync_interner_across_threads() {
+    extern crate std;
+    use alloc::sync::Arc;
+    use std::thread;
+
+    let interner = Arc::new(crate::SyncByteStrInterner::new());
+    interner.intern_static("static");
+    let mut handles = vec![];
+    for i in 0..8 {
+        let interner = Arc::clone(&interner);
+        handles.push(thread::spawn(move || {
+            let key = if i % 2 == 0 { "even" } else { "odd" };
+            (interner.intern(key), interner.intern("static"))
+        }));
+    }
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    let even = interner.get("even").unwrap();
+    for (i, (key, seeded)) in results.iter().enumerate() {
+        assert!(seeded.is_static());
+        if i % 2 == 0 {
+            assert!(ByteStr::ptr_eq(key, &even));
+        } else {
+            assert_eq!(key, "odd");
+        }
+    }
+
+    let stats = interner.stats();
+    assert_eq!(stats.entries, 3);
+    assert_eq!(stats.hits + stats.misses, 16);
+    assert_eq!(stats.misses, 2);
+
+    drop(results);
+    drop(even);
+    assert_eq!(interner.evict_unused(), 2);
+    let inner = Arc::try_unwrap(interner).unwrap().into_inner();
+    assert_eq!(inner.len(), 1);
+    assert_eq!(inner.stats().hits, stats.hits);
+}
+
+// Macro related tests
+const CONST_BYTESTR: ByteStr = crate::bytestr!("const ", 1, ' ', true);
+
+#[test]
+fn test_bytestr_macro() {
+    assert_eq!(CONST_BYTESTR, "const 1 true");
+    assert!(CONST_BYTESTR.is_static());
+    assert_eq!(crate::bytestr!("trailing",), "trailing");
+    assert_eq!(crate::bytestr!(concat!("a", "b"), "c"), "abc");
+    assert!(crate::bytestr!().is_empty());
+}
+
+#[test]
+fn test_format_bytestr_matches_format() {
+    let name = "wörld";
+    for n in [0, 1, 10, 100] {
+        let padding = "-".repeat(n);
+        let ours = crate::format_bytestr!("{padding}hello {name}! {n:>4} {:?}", 'x');
+        let theirs = format!("{padding}hello {name}! {n:>4} {:?}", 'x');
+        assert_eq!(ours, theirs);
+    }
+}
+
+#[test]
+fn test_format_bytestr_storage() {
+    let literal = crate::format_bytestr!("no arguments");
+    assert!(literal.is_static());
+
+    let n = 42;
+    let short = crate::format_bytestr!("{n}");
+    assert!(is_inline(&short));
+
+    let exact = crate::format_bytestr!("{}", "x".repeat(ByteStr::INLINE_CAPACITY));
+    assert!(is_inline(&exact));
+
+    let long = crate::format_bytestr!("{}{}", "x".repeat(40), "y".repeat(40));
+    assert!(!is_inline(&long));
+    assert!(long.is_unique());
+    assert_eq!(long.len(), 80);
+    assert_eq!(long.capacity(), 80);
+
+    let grown = crate::format_bytestr!("{0}{0}{0}", "x".repeat(40));
+    assert_eq!(grown.len(), 120);
+    assert_eq!(grown.capacity(), 120);
+}
+
+#[test]
+#[should_panic(expected = "a formatting trait implementation returned an error")]
+fn test_format_bytestr_propagates_formatting_errors() {
+    struct Failing;
+
+    impl core::fmt::Display for Failing {
+        fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+
+    let _ = crate::format_bytestr!("{Failing}");
+}
+
+// Rope related tests
+fn rope_of(segments: &[&str]) -> ByteRope {
+    segments.iter().map(|s| ByteStr::from(*s)).collect()
+}
+
+#[test]
+fn test_rope_push_and_concat() {
+    let source = ByteStr::from_static("Hello, world!");
+    let mut rope = ByteRope::new();
+    assert!(rope.is_empty());
+    rope.push(source.slice(..5));
+    rope.push(ByteStr::new());
+    rope.push(source.slice(5..));
+    assert_eq!(rope.len(), 13);
+    assert_eq!(rope.segments().len(), 2);
+    assert!(ByteStr::ptr_eq(&rope.segments()[0], &source.slice(..5)));
+
+    rope.concat(rope_of(&["", " ", "bye"]));
+    assert_eq!(rope, "Hello, world! bye");
+    assert_eq!(rope.len(), 17);
+    assert_eq!(rope.segments().len(), 4);
+}
+
+#[test]
+fn test_rope_insert() {
+    let mut rope = rope_of(&["ab", "cd"]);
+    rope.insert(0, ByteStr::from("<"));
+    rope.insert(3, ByteStr::from("|"));
+    rope.insert(rope.len(), ByteStr::from(">"));
+    rope.insert(4, ByteStr::from("-"));
+    assert_eq!(rope, "<ab|-cd>");
+    assert_eq!(rope.segments(), ["<", "ab", "|", "-", "cd", ">"]);
+
+    rope.insert(2, ByteStr::from("x"));
+    assert_eq!(rope, "<axb|-cd>");
+    assert_eq!(rope.len(), 9);
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary")]
+fn test_rope_insert_inside_char() {
+    rope_of(&["é"]).insert(1, ByteStr::from("x"));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_rope_insert_out_of_bounds() {
+    rope_of(&["ab"]).insert(3, ByteStr::from("x"));
+}
+
+#[test]
+fn test_rope_slice() {
+    let rope = rope_of(&["ab", "cé", "ef"]);
+    assert_eq!(rope.slice(..), "abcéef");
+    assert_eq!(rope.slice(1..5), "bcé");
+    assert_eq!(rope.slice(1..5).segments(), ["b", "cé"]);
+    assert_eq!(rope.slice(2..2), "");
+    assert!(rope.slice(2..2).segments().is_empty());
+    assert_eq!(rope.slice(5..), "ef");
+    assert_eq!(
+        rope.try_slice(..4),
+        Err(SliceError::NotCharBoundary { index: 4 })
+    );
+    assert_eq!(
+        rope.try_slice(3..8),
+        Err(SliceError::OutOfBounds {
+            start: 3,
+            end: 8,
+            len: 7
+        })
+    );
+    assert!(rope.is_char_boundary(7));
+    assert!(!rope.is_char_boundary(4));
+}
+
+#[test]
+fn test_rope_chars() {
+    let rope = rope_of(&["h", "é", "", "llo", "世界"]);
+    assert!(rope.chars().eq("héllo世界".chars()));
+    assert!(rope.chars().rev().eq("héllo世界".chars().rev()));
+    assert_eq!(ByteRope::new().chars().next(), None);
+}
+
+#[test]
+fn test_rope_lines() {
+    for (segments, expected) in [
+        (&["a\nb", "c\r", "\nd"][..], &["a", "bc", "d"][..]),
+        (&["line\n"], &["line"]),
+        (&["\n", "\n"], &["", ""]),
+        (&["no newline"], &["no newline"]),
+        (&["bare\r"], &["bare\r"]),
+        (&["a", "\r\n", "\r", "\n"], &["a", ""]),
+        (&[], &[]),
+    ] {
+        let lines: Vec<ByteRope> = rope_of(segments).lines().collect();
+        assert_eq!(lines, expected, "{segments:?}");
+        let flat: String = segments.concat();
+        assert!(flat.lines().eq(expected.iter().copied()));
+    }
+}
+
+#[test]
+fn test_rope_comparison() {
+    let a = rope_of(&["ab", "cd", "e"]);
+    let b = rope_of(&["a", "bcde"]);
+    assert_eq!(a, b);
+    assert_eq!(a, "abcde");
+    assert_eq!("abcde", a);
+    assert_eq!(a, ByteStr::from("abcde"));
+    assert_eq!(ByteStr::from("abcde"), a);
+    assert_eq!(a, String::from("abcde"));
+    assert_ne!(a, "abcd");
+    assert_ne!(a, rope_of(&["abcdf"]));
+
+    let mut sorted = [
+        rope_of(&["b"]),
+        rope_of(&["ab", "c"]),
+        rope_of(&["a", "b"]),
+        ByteRope::new(),
+        rope_of(&["a", "bd"]),
+    ];
+    sorted.sort();
+    let flat: Vec<ByteStr> = sorted.iter().map(ByteRope::flatten).collect();
+    assert_eq!(flat, ["", "ab", "abc", "abd", "b"]);
+}
+
+#[test]
+fn test_rope_hash() {
+    extern crate std;
+    use core::hash::{BuildHasher, Hash, Hasher};
+    use std::hash::RandomState;
+
+    // Records every call, so hashes only match if the calls do.
+    #[derive(Default)]
+    struct Recorder(Vec<Vec<u8>>);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.push(bytes.to_vec());
+        }
+    }
+
+    fn calls(value: &impl Hash) -> Vec<Vec<u8>> {
+        let mut recorder = Recorder::default();
+        value.hash(&mut recorder);
+        recorder.0
+    }
+
+    let long = "x".repeat(100);
+    let a = rope_of(&[&long[..10], &long[10..70], &long[70..]]);
+    let b = rope_of(&[&long]);
+    assert_eq!(calls(&a), calls(&b));
+    assert_eq!(calls(&rope_of(&["ab", "c"])), calls(&"abc"));
+    assert_eq!(
+        calls(&rope_of(&[&long[..30], &long[30..64]])),
+        calls(&&long[..64])
+    );
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(&a), state.hash_one(long.as_str()));
+    assert_eq!(state.hash_one(ByteRope::new()), state.hash_one(""));
+}
+
+#[test]
+fn test_rope_flatten() {
+    assert_eq!(ByteRope::new().flatten(), "");
+
+    let single = ByteStr::from("a single segment that is much too long to be stored inline");
+    let flat = ByteRope::from(single.clone()).flatten();
+    assert!(ByteStr::ptr_eq(&flat, &single));
+
+    let rope = rope_of(&["Hello", ", ", "world"]);
+    assert_eq!(ByteStr::from(rope.clone()), "Hello, world");
+    assert_eq!(format!("{rope}"), "Hello, world");
+    assert_eq!(format!("{rope:?}"), r#"["Hello", ", ", "world"]"#);
+}
+
+// Buf related tests
+#[test]
+fn test_buf_for_bytestr() {
+    use bytes::Buf;
+
+    let source = ByteStr::from_static("GET /index.html HTTP/1.1 with some trailing text");
+    let mut s = source.clone();
+    assert_eq!(s.remaining(), source.len());
+    assert_eq!(s.chunk(), source.as_bytes());
+
+    let method = s.copy_to_bytes(3);
+    assert_eq!(method.as_ref(), b"GET");
+    assert_eq!(method.as_ptr(), source.as_ptr());
+    assert_eq!(s.get_u8(), b' ');
+    assert!(s.is_static());
+    assert_eq!(s.as_ptr(), source[4..].as_ptr());
+
+    let mut inline = ByteStr::from("héllo");
+    inline.advance(3);
+    assert_eq!(inline, "llo");
+    assert_eq!(inline.copy_to_bytes(3).as_ref(), b"llo");
+    assert!(!inline.has_remaining());
+}
+
+#[test]
+#[should_panic(expected = "byte index 2 is not a char boundary")]
+fn test_buf_for_bytestr_advance_inside_char() {
+    use bytes::Buf;
+
+    ByteStr::from("héllo").advance(2);
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary")]
+fn test_buf_for_bytestr_get_u8_inside_char() {
+    use bytes::Buf;
+
+    let _ = ByteStr::from("é").get_u8();
+}
+
+#[test]
+fn test_bytestr_buf_advance() {
+    use bytes::Buf;
+
+    let mut buf: ByteStrBuf = ["ab", "", "é", "cd"]
+        .into_iter()
+        .map(ByteStr::from)
+        .collect();
+    assert_eq!(buf.remaining(), 6);
+    assert_eq!(buf.segment_count(), 3);
+    assert_eq!(buf.chunk(), b"ab");
+
+    buf.advance(3);
+    assert_eq!(buf.chunk(), &"é".as_bytes()[1..]);
+    assert_eq!(buf.remaining(), 3);
+    buf.advance(1);
+    assert_eq!(buf.chunk(), b"cd");
+    assert_eq!(buf.segment_count(), 1);
+
+    buf.advance(2);
+    assert!(buf.is_empty());
+    assert_eq!(buf.chunk(), b"");
+    assert_eq!(buf.segment_count(), 0);
+    buf.advance(0);
+
+    buf.push(ByteStr::from("next"));
+    buf.clear();
+    assert!(buf.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "cannot advance past `remaining`")]
+fn test_bytestr_buf_advance_past_end() {
+    use bytes::Buf;
+
+    ByteStrBuf::from(ByteStr::from("ab")).advance(3);
+}
+
+#[test]
+fn test_bytestr_buf_copy_to_bytes() {
+    use bytes::Buf;
+
+    let first = ByteStr::from_static("a first segment that lives in static memory");
+    let mut buf = ByteStrBuf::from(first.clone());
+    buf.push(ByteStr::from("second"));
+
+    let head = buf.copy_to_bytes(8);
+    assert_eq!(head.as_ref(), b"a first ");
+    assert_eq!(head.as_ptr(), first.as_ptr());
+
+    let rest = first.len() - 8;
+    let spanning = buf.copy_to_bytes(rest + 3);
+    assert_eq!(spanning.as_ref(), b"segment that lives in static memorysec");
+    assert_eq!(buf.chunk(), b"ond");
+}
+
+#[test]
+fn test_bytestr_buf_from_rope() {
+    use bytes::Buf;
+
+    let rope: ByteRope = ["Hello", ", ", "world"]
+        .into_iter()
+        .map(ByteStr::from)
+        .collect();
+    let mut buf = ByteStrBuf::from(rope.clone());
+    assert_eq!(buf.remaining(), rope.len());
+    assert_eq!(buf.copy_to_bytes(rope.len()).as_ref(), b"Hello, world");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_bytestr_buf_chunks_vectored() {
+    extern crate std;
+    use bytes::Buf;
+    use std::io::{IoSlice, Write};
+
+    let mut buf: ByteStrBuf = ["HTTP/1.1 200 OK\r\n", "\r\n", "body"]
+        .into_iter()
+        .map(ByteStr::from)
+        .collect();
+    buf.advance(9);
+
+    let mut slices = [IoSlice::new(&[]); 4];
+    assert_eq!(buf.chunks_vectored(&mut slices), 3);
+    assert_eq!(&*slices[0], b"200 OK\r\n");
+    assert_eq!(&*slices[1], b"\r\n");
+    assert_eq!(&*slices[2], b"body");
+
+    let mut few = [IoSlice::new(&[]); 2];
+    assert_eq!(buf.chunks_vectored(&mut few), 2);
+    assert_eq!(&*few[1], b"\r\n");
+
+    let mut out = Vec::new();
+    while buf.has_remaining() {
+        let n = {
+            let mut slices = [IoSlice::new(&[]); 4];
+            let count = buf.chunks_vectored(&mut slices);
+            out.write_vectored(&slices[..count]).unwrap()
+        };
+        buf.advance(n);
+    }
+    assert_eq!(out, b"200 OK\r\n\r\nbody");
+}
+
+// Case conversion and replacement related tests
+#[test]
+fn test_case_conversion_reuses_unchanged_strings() {
+    let lower = ByteStr::from_static("already lower case, ünïcode too");
+    let upper = ByteStr::from_static("ALREADY UPPER CASE, ÜNÏCODE TOO");
+    assert!(ByteStr::ptr_eq(&lower.to_ascii_lowercase(), &lower));
+    assert!(ByteStr::ptr_eq(&lower.to_lowercase(), &lower));
+    assert!(ByteStr::ptr_eq(&upper.to_ascii_uppercase(), &upper));
+    assert!(ByteStr::ptr_eq(&upper.to_uppercase(), &upper));
+    assert!(ByteStr::ptr_eq(
+        &ByteStr::new().to_uppercase(),
+        &ByteStr::new()
+    ));
+}
+
+#[test]
+fn test_case_conversion_matches_str() {
+    for s in [
+        "Hello, World!",
+        "ÜBER straße",
+        "ὈΔΥΣΣΕΎΣ",
+        "ǅ",
+        "İ",
+        "12345",
+        "",
+    ] {
+        let bytestr = ByteStr::from(s);
+        assert_eq!(bytestr.to_ascii_lowercase(), s.to_ascii_lowercase());
+        assert_eq!(bytestr.to_ascii_uppercase(), s.to_ascii_uppercase());
+        assert_eq!(bytestr.to_lowercase(), s.to_lowercase());
+        assert_eq!(bytestr.to_uppercase(), s.to_uppercase());
+    }
+}
+
+#[test]
+fn test_replace_matches_str() {
+    let cases: &[(&str, &str, &str)] = &[
+        ("a,b,,c", ",", ";"),
+        ("aaaa", "aa", "b"),
+        ("abc", "", "-"),
+        ("héllo wörld", "ö", "o"),
+        ("no match", "x", "y"),
+        ("", "", "x"),
+    ];
+    for &(s, from, to) in cases {
+        let bytestr = ByteStr::from(s);
+        assert_eq!(bytestr.replace(from, to), s.replace(from, to));
+        for count in 0..4 {
+            assert_eq!(
+                bytestr.replacen(from, to, count),
+                s.replacen(from, to, count)
+            );
+        }
+    }
+    let s = ByteStr::from("a1b22c");
+    assert_eq!(s.replace(|c: char| c.is_ascii_digit(), "#"), "a#b##c");
+    assert_eq!(s.replace(['a', 'c'], ""), "1b22");
+}
+
+#[test]
+fn test_replace_reuses_unchanged_strings() {
+    let s = ByteStr::from_static("key=value; other=value");
+    assert!(ByteStr::ptr_eq(&s.replace('#', "!"), &s));
+    assert!(ByteStr::ptr_eq(&s.replace("value", "value"), &s));
+    assert!(ByteStr::ptr_eq(&s.replacen('=', ":", 0), &s));
+
+    let partly = s.replace("=value", "=value2");
+    assert_eq!(partly, "key=value2; other=value2");
+    let mixed = ByteStr::from_static("a.b-c").replace(['.', '-'], "-");
+    assert_eq!(mixed, "a-b-c");
+}
+
+#[test]
+fn test_trim_matches() {
+    let s = ByteStr::from_static("xxhello worldxx");
+    assert_eq!(s.trim_matches('x'), "hello world");
+    assert_eq!(s.trim_start_matches('x'), "hello worldxx");
+    assert_eq!(s.trim_end_matches("xx"), "xxhello world");
+    assert_eq!(s.trim_start_matches(""), s);
+    assert_eq!(s.trim_matches(['x', 'h', 'd']), "ello worl");
+    assert!(ByteStr::ptr_eq(&s.trim_matches('y'), &s));
+    assert_eq!(s.trim_start_matches(|c: char| c != ' '), " worldxx");
+    assert_eq!(ByteStr::from("xxxx").trim_matches('x'), "");
+
+    let trimmed = s.trim_matches('x');
+    assert_eq!(trimmed.as_ptr(), s[2..].as_ptr());
+}
+
+// Case-insensitive related tests
+#[test]
+fn test_case_insensitive_eq_and_ord() {
+    let a = CaseInsensitive::new(ByteStr::from("Content-Type"));
+    let b = CaseInsensitive::new(ByteStr::from("CONTENT-type"));
+    assert_eq!(a, b);
+    assert_eq!(a, "content-TYPE");
+    assert_eq!(a, CaseInsensitive::new("content-type"));
+    assert_ne!(a, CaseInsensitive::new("content-length"));
+    assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+    assert_eq!(a.as_str(), "Content-Type");
+    assert_eq!(format!("{b}"), "CONTENT-type");
+    assert_eq!(format!("{a:?}"), r#"CaseInsensitive("Content-Type")"#);
+
+    // Non-ASCII characters are compared exactly
+    assert_ne!(CaseInsensitive::new("Ü"), CaseInsensitive::new("ü"));
+
+    let mut keys = ["b", "A", "a", "C", "_"].map(CaseInsensitive::new);
+    keys.sort();
+    assert_eq!(
+        keys.map(CaseInsensitive::into_inner),
+        ["_", "A", "a", "b", "C"]
+    );
+}
+
+#[test]
+fn test_case_insensitive_hash_ignores_case() {
+    extern crate std;
+    use core::hash::BuildHasher;
+    use std::hash::RandomState;
+
+    let state = RandomState::new();
+    let long = "X-Some-Very-Long-Header-Name-".repeat(5);
+    for s in ["", "Host", long.as_str()] {
+        let upper = CaseInsensitive::new(s.to_ascii_uppercase());
+        let lower = CaseInsensitive::new(s.to_ascii_lowercase());
+        assert_eq!(state.hash_one(&upper), state.hash_one(&lower));
+        assert_eq!(state.hash_one(&upper), state.hash_one(upper.as_caseless()));
+    }
+}
+
+#[test]
+fn test_case_insensitive_map_lookup() {
+    extern crate std;
+    use alloc::collections::BTreeMap;
+    use std::collections::HashMap;
+
+    let mut headers = HashMap::new();
+    headers.insert(CaseInsensitive::new(ByteStr::from("Content-Length")), 42);
+    headers.insert(CaseInsensitive::new(ByteStr::from("HOST")), 1);
+    assert_eq!(
+        headers.get(CaseInsensitiveStr::new("content-length")),
+        Some(&42)
+    );
+    assert_eq!(headers.get(CaseInsensitiveStr::new("Host")), Some(&1));
+    assert_eq!(headers.get(CaseInsensitiveStr::new("accept")), None);
+
+    headers.insert(CaseInsensitive::new(ByteStr::from("host")), 2);
+    assert_eq!(headers.len(), 2);
+    let (name, value) = headers
+        .get_key_value(CaseInsensitiveStr::new("host"))
+        .unwrap();
+    assert_eq!((name.get().as_str(), *value), ("HOST", 2));
+
+    let keywords: BTreeMap<CaseInsensitive<&str>, u8> = [("SELECT", 1), ("from", 2)]
+        .map(|(k, v)| (CaseInsensitive::new(k), v))
+        .into();
+    assert_eq!(
+        keywords.get(<&CaseInsensitiveStr>::from("select")),
+        Some(&1)
+    );
+    assert_eq!(keywords.get(CaseInsensitiveStr::new("FROM")), Some(&2));
+}
+
+// Unicode normalization related tests
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_normalization_forms() {
+    let composed = ByteStr::from_static("Ame\u{301}lie \u{fb01}ance\u{301}e");
+    assert!(!composed.is_nfc());
+    assert_eq!(composed.nfc(), "Am\u{e9}lie \u{fb01}anc\u{e9}e");
+    assert!(composed.nfc().is_nfc());
+    assert!(composed.is_nfd());
+    assert!(ByteStr::ptr_eq(&composed.nfd(), &composed));
+    assert_eq!(composed.nfkc(), "Am\u{e9}lie fianc\u{e9}e");
+    assert_eq!(composed.nfkd(), "Ame\u{301}lie fiance\u{301}e");
+    assert!(!composed.is_nfkd());
+
+    let ascii = ByteStr::from_static("plain ascii stays shared in every form");
+    for normalized in [ascii.nfc(), ascii.nfd(), ascii.nfkc(), ascii.nfkd()] {
+        assert!(ByteStr::ptr_eq(&normalized, &ascii));
+    }
+    assert_eq!(ByteStr::new().nfc(), "");
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_normalized_eq_and_hash() {
+    extern crate std;
+    use crate::Normalized;
+    use core::hash::BuildHasher;
+    use std::hash::RandomState;
+
+    let state = RandomState::new();
+    let long_composed = "\u{e9}".repeat(40);
+    let long_decomposed = "e\u{301}".repeat(40);
+    let pairs = [
+        ("Zo\u{eb}", "Zoe\u{308}"),
+        ("\u{212b}", "\u{c5}"),
+        ("q\u{307}\u{323}", "q\u{323}\u{307}"),
+        (long_composed.as_str(), long_decomposed.as_str()),
+    ];
+    for (a, b) in pairs {
+        let a = Normalized::new(ByteStr::from(a));
+        let b = Normalized::new(ByteStr::from(b));
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+        assert_eq!(state.hash_one(&a), state.hash_one(&b));
+    }
+
+    let name = Normalized::new(ByteStr::from("Zo\u{eb}"));
+    assert_eq!(name, "Zoe\u{308}");
+    assert_ne!(name, "Zoe");
+    assert_ne!(Normalized::new("\u{fb01}"), Normalized::new("fi"));
+    assert_eq!(name.as_str(), "Zo\u{eb}");
+    assert!(Normalized::new("e\u{301}") > Normalized::new("e"));
+}
+
+// Unicode segmentation related tests
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn test_segmentation_yields_zero_copy_slices() {
+    let source = ByteStr::from_static("Hello, wörld! Ça va? 👍🏽 Oui.");
+    let base = source.as_ptr() as usize;
+    let offset = |s: &ByteStr| s.as_ptr() as usize - base;
+
+    for grapheme in source.graphemes() {
+        assert!(!is_inline(&grapheme));
+        assert_eq!(&source[offset(&grapheme)..][..grapheme.len()], grapheme);
+    }
+    for (index, grapheme) in source.grapheme_indices() {
+        assert_eq!(offset(&grapheme), index);
+    }
+    let words: Vec<ByteStr> = source.unicode_words().collect();
+    assert_eq!(words, ["Hello", "wörld", "Ça", "va", "Oui"]);
+    assert_eq!(offset(&words[1]), 7);
+
+    let bounds: ByteRope = source.split_word_bounds().collect();
+    assert_eq!(bounds, source);
+    let sentences: Vec<ByteStr> = source.sentences().collect();
+    assert_eq!(sentences, ["Hello, wörld! ", "Ça va? ", "👍🏽 Oui."]);
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn test_segmentation_double_ended() {
+    let s = ByteStr::from("a\u{308}🇯🇵b");
+    let reversed: Vec<ByteStr> = s.graphemes().rev().collect();
+    assert_eq!(reversed, ["b", "🇯🇵", "a\u{308}"]);
+    assert_eq!(s.grapheme_indices().next_back().unwrap().0, 11);
+    assert_eq!(
+        ByteStr::from("one two")
+            .unicode_words()
+            .next_back()
+            .unwrap(),
+        "two"
+    );
+    assert_eq!(
+        ByteStr::from("one two")
+            .split_word_bounds()
+            .next_back()
+            .unwrap(),
+        "two"
+    );
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn test_truncate_graphemes() {
+    let mut s = ByteStr::from("ne\u{301}e 👩‍💻 done");
+    s.truncate_graphemes(10);
+    assert_eq!(s, "ne\u{301}e 👩‍💻 done");
+    s.truncate_graphemes(5);
+    assert_eq!(s, "ne\u{301}e 👩‍💻");
+    s.truncate_graphemes(2);
+    assert_eq!(s, "ne\u{301}");
+    s.truncate_graphemes(0);
+    assert!(s.is_empty());
+}
+
+// Display width related tests
+#[cfg(feature = "unicode-width")]
+#[test]
+fn test_display_width() {
+    assert_eq!(ByteStr::new().display_width(), 0);
+    assert_eq!(ByteStr::from("abc").display_width(), 3);
+    assert_eq!(ByteStr::from("한국어").display_width(), 6);
+    assert_eq!(ByteStr::from("👍").display_width(), 2);
+    assert_eq!(ByteStr::from("a\u{301}\u{308}").display_width(), 1);
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn test_truncate_to_width() {
+    let s = ByteStr::from_static("ab中文cd and some more text after it");
+    assert_eq!(s.truncate_to_width(0), "");
+    assert_eq!(s.truncate_to_width(3), "ab");
+    assert_eq!(s.truncate_to_width(4), "ab中");
+    assert_eq!(s.truncate_to_width(7), "ab中文c");
+    assert_eq!(s.truncate_to_width(1000), s);
+    assert_eq!(s.truncate_to_width(4).as_ptr(), s.as_ptr());
+
+    let accented = ByteStr::from("e\u{301}e\u{301}e\u{301}");
+    assert_eq!(accented.truncate_to_width(2), "e\u{301}e\u{301}");
+
+    // A variation selector makes the heart two columns wide, so it has to go
+    let heart = ByteStr::from("a\u{2764}\u{fe0f}");
+    assert_eq!(heart.display_width(), 3);
+    assert_eq!(heart.truncate_to_width(2), "a\u{2764}");
+    assert_eq!(heart.truncate_to_width(3), heart);
+    for cols in 0..5 {
+        assert!(heart.truncate_to_width(cols).display_width() <= cols);
+    }
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn test_truncate_to_width_with_ellipsis() {
+    let s = ByteStr::from_static("日本語のテキスト");
+    assert!(ByteStr::ptr_eq(&s.truncate_to_width_with_ellipsis(16), &s));
+    assert_eq!(s.truncate_to_width_with_ellipsis(15), "日本語のテキス…");
+    assert_eq!(s.truncate_to_width_with_ellipsis(14), "日本語のテキ…");
+    assert_eq!(s.truncate_to_width_with_ellipsis(6), "日本…");
+    assert_eq!(s.truncate_to_width_with_ellipsis(2), "…");
+    assert_eq!(s.truncate_to_width_with_ellipsis(1), "…");
+    assert_eq!(s.truncate_to_width_with_ellipsis(0), "");
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn test_aligned_display() {
+    let wide = ByteStr::from("中文");
+    let narrow = ByteStr::from("abcd");
+    assert_eq!(format!("{}", wide.aligned()), "中文");
+    assert_eq!(
+        format!("|{:6}|{:6}|", wide.aligned(), narrow.aligned()),
+        "|中文  |abcd  |"
+    );
+    assert_eq!(format!("|{:>6}|", wide.aligned()), "|  中文|");
+    assert_eq!(format!("|{:-^7}|", wide.aligned()), "|-中文--|");
+    assert_eq!(format!("|{:2}|", wide.aligned()), "|中文|");
+    assert_eq!(format!("|{:.3}|", wide.aligned()), "|中|");
+    assert_eq!(format!("|{:4.3}|", wide.aligned()), "|中  |");
+    assert_eq!(
+        format!("|{:>w$.p$}|", narrow.aligned(), w = 5, p = 2),
+        "|   ab|"
+    );
+}
+
+// Line index related tests
+
+#[test]
+fn test_line_index_line_col_round_trip() {
+    let text = "ab\nπ😀c\r\n\nü";
+    let index = LineIndex::new(ByteStr::from(text));
+    assert_eq!(index.line_count(), 4);
+
+    for offset in 0..=text.len() {
+        let Some(position) = index.line_col(offset) else {
+            assert!(!text.is_char_boundary(offset));
+            continue;
+        };
+        let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let before = &text[line_start..offset];
+        assert_eq!(position.line, text[..offset].matches('\n').count());
+        assert_eq!(position.col, before.len());
+        assert_eq!(index.offset(position), Some(offset));
+
+        let utf16 = index.bytes_to_utf16(position).unwrap();
+        assert_eq!(utf16.col, before.encode_utf16().count());
+        assert_eq!(index.utf16_to_bytes(utf16), Some(position));
+
+        let chars = index.bytes_to_chars(position).unwrap();
+        assert_eq!(chars.col, before.chars().count());
+        assert_eq!(index.chars_to_bytes(chars), Some(position));
+    }
+    assert_eq!(index.line_col(text.len() + 1), None);
+}
+
+#[test]
+fn test_line_index_rejects_invalid_positions() {
+    let index = LineIndex::new(ByteStr::from("a😀b\nc"));
+    assert_eq!(index.offset(LineCol { line: 0, col: 2 }), None);
+    assert_eq!(index.offset(LineCol { line: 0, col: 7 }), None);
+    assert_eq!(index.offset(LineCol { line: 1, col: 1 }), Some(8));
+    assert_eq!(index.offset(LineCol { line: 1, col: 2 }), None);
+    assert_eq!(index.offset(LineCol { line: 2, col: 0 }), None);
+    assert_eq!(index.bytes_to_utf16(LineCol { line: 0, col: 3 }), None);
+
+    assert_eq!(index.utf16_to_bytes(LineCol { line: 0, col: 2 }), None);
+    assert_eq!(
+        index.utf16_to_bytes(LineCol { line: 0, col: 3 }),
+        Some(LineCol { line: 0, col: 5 })
+    );
+    assert_eq!(index.utf16_to_bytes(LineCol { line: 0, col: 5 }), None);
+    assert_eq!(
+        index.chars_to_bytes(LineCol { line: 0, col: 2 }),
+        Some(LineCol { line: 0, col: 5 })
+    );
+    assert_eq!(index.chars_to_bytes(LineCol { line: 0, col: 4 }), None);
+    assert_eq!(
+        index.chars_to_bytes(LineCol {
+            line: 1,
+            col: usize::MAX
+        }),
+        None
+    );
+}
+
+#[test]
+fn test_line_index_lines() {
+    let source = ByteStr::from_static("one\r\ntwo\n\nthree");
+    let index = LineIndex::new(source.clone());
+    assert!(ByteStr::ptr_eq(index.source(), &source));
+
+    let lines: Vec<_> = (0..index.line_count())
+        .map(|n| index.line(n).unwrap())
+        .collect();
+    assert_eq!(lines, ["one", "two", "", "three"]);
+    assert_eq!(index.line(4), None);
+
+    let empty = LineIndex::new(ByteStr::new());
+    assert_eq!(empty.line_count(), 1);
+    assert_eq!(empty.line(0).unwrap(), "");
+    assert_eq!(empty.line_col(0), Some(LineCol::default()));
+}
+
+#[test]
+fn test_line_index_location() {
+    let text = "x = 1\ny = \"é\" + x\n";
+    let index = LineIndex::new(ByteStr::from(text));
+    let mut cursor = ByteStrCursor::new(ByteStr::from(text));
+    while !cursor.is_empty() {
+        assert_eq!(index.location(cursor.offset()), Some(cursor.location()));
+        cursor.next_char();
+    }
+    assert_eq!(index.location(cursor.offset()), Some(cursor.location()));
+    assert_eq!(index.location(12), None);
+}
+
+#[test]
+fn test_line_index_offset_of() {
+    let short = ByteStr::from("key = value");
+    let index = LineIndex::new(short.clone());
+    let value = index.source().slice(6..);
+    assert_eq!(index.offset_of(&value), Some(6));
+    assert_eq!(index.offset_of(&index.source().slice(2..3)), Some(2));
+    assert_eq!(index.offset_of(&short.slice(6..)), Some(6));
+
+    let long = ByteStr::from("[section]\nfirst_key = 1\nsecond_key = \"some longer value\"\n");
+    let index = LineIndex::new(long.clone());
+    let key = long.lines().nth(2).unwrap().split(' ').next().unwrap();
+    assert_eq!(key, "second_key");
+    let offset = index.offset_of(&key).unwrap();
+    assert_eq!(index.line_col(offset), Some(LineCol { line: 2, col: 0 }));
+    assert_eq!(index.offset_of(&long.slice(long.len()..)), Some(long.len()));
+    assert_eq!(index.offset_of(&ByteStr::from("second_key")), None);
+}
+
+#[test]
+fn test_line_index_offset_of_short_source() {
+    let source = ByteStr::from("a = 1");
+    assert!(is_inline(&source));
+    let index = LineIndex::new(source.clone());
+    assert!(is_inline(index.source()));
+
+    let value = source.slice(4..);
+    let offset = index.offset_of(&value).unwrap();
+    assert_eq!(offset, 4);
+    assert_eq!(index.line_col(offset), Some(LineCol { line: 0, col: 4 }));
+    let (name, _) = source.split_once(" = ").unwrap();
+    assert_eq!(index.offset_of(&name), Some(0));
+    assert_eq!(index.offset_of(&ByteStr::from("1")), None);
+}
+
+// Span related tests
+
+#[test]
+fn test_offset_in_locates_zero_copy_slices() {
+    let text = ByteStr::from("[server]\nhost = \"example.com\"\nport = 8080\n".repeat(2));
+    let line = text.lines().nth(2).unwrap();
+    let (key, value) = line.split_once(" = ").unwrap();
+    assert_eq!(line.offset_in(&text), Some(30..41));
+    assert_eq!(key.offset_in(&text), Some(30..34));
+    assert_eq!(value.offset_in(&text), Some(37..41));
+    assert_eq!(value.offset_in(&line), Some(7..11));
+    assert_eq!(text.offset_in(&text), Some(0..text.len()));
+    assert_eq!(text.slice(5..5).offset_in(&text), Some(5..5));
+
+    assert!(key.is_subslice_of(&line));
+    assert!(!line.is_subslice_of(&key));
+    assert!(!value.is_subslice_of(&key));
+    assert_eq!(ByteStr::from("port".repeat(20)).offset_in(&text), None);
+}
+
+#[test]
+fn test_offset_in_locates_inline_slices() {
+    let short = ByteStr::from("key=value");
+    assert!(is_inline(&short));
+    let (key, value) = short.split_once('=').unwrap();
+    assert!(is_inline(&value));
+    assert_eq!(key.offset_in(&short), Some(0..3));
+    assert_eq!(value.offset_in(&short), Some(4..9));
+    assert_eq!(value.slice(1..3).offset_in(&short), Some(5..7));
+    assert_eq!(value.slice(1..3).offset_in(&value), Some(1..3));
+    assert_eq!(short.slice_ref(&short[4..]).offset_in(&short), Some(4..9));
+    assert_eq!(short.slice(3..3).offset_in(&short), Some(3..3));
+    assert_eq!(short.clone().offset_in(&short), Some(0..9));
+    assert!(value.is_subslice_of(&short));
+
+    assert_eq!(short.offset_in(&value), None);
+    assert_eq!(key.offset_in(&value), None);
+    assert_eq!(ByteStr::from("value").offset_in(&short), None);
+    assert_eq!(value.offset_in(&ByteStr::from("key=value".repeat(4))), None);
+
+    let spanned = value.spanned_in(&short).unwrap();
+    assert_eq!(spanned.span(), 4..9);
+}
+
+#[test]
+fn test_offset_in_locates_empty_subsets() {
+    for text in [String::from("k="), format!("{}=", "k".repeat(40))] {
+        let s = ByteStr::from(text);
+        let len = s.len();
+        let (_, value) = s.split_once('=').unwrap();
+        assert_eq!(value.offset_in(&s), Some(len..len));
+        assert_eq!(
+            s.strip_prefix(s.as_str()).unwrap().offset_in(&s),
+            Some(len..len)
+        );
+        assert_eq!(
+            s.strip_suffix(s.as_str()).unwrap().offset_in(&s),
+            Some(0..0)
+        );
+        assert_eq!(s.slice(1..1).trim().offset_in(&s), Some(1..1));
+    }
+
+    let blank = ByteStr::from("   ");
+    assert!(
+        blank
+            .trim()
+            .offset_in(&blank)
+            .is_some_and(|range| range.is_empty())
+    );
+    // An empty subset from elsewhere is still accepted, without an offset.
+    let s = ByteStr::from("k=");
+    assert_eq!(s.slice_ref("").offset_in(&s), None);
+}
+
+#[test]
+fn test_same_buffer() {
+    let text = ByteStr::from("one two three four five six seven eight nine ten");
+    let words: Vec<_> = text.split(' ').collect();
+    assert!(words[0].same_buffer(&words[9]));
+    assert!(words[3].same_buffer(&text));
+
+    let other = ByteStr::from(String::from(text.as_str()));
+    assert!(!other.same_buffer(&text));
+    assert!(!ByteStr::new().same_buffer(&ByteStr::new()));
+
+    // Inline strings are views of the inline string they were sliced from,
+    // which is compared by value.
+    let short = ByteStr::from("one two");
+    assert!(short.slice(..3).same_buffer(&short.slice(4..)));
+    assert!(short.slice(..3).same_buffer(&ByteStr::from("one two")));
+    assert!(!short.slice(..3).same_buffer(&ByteStr::from("one")));
+    assert!(!short.same_buffer(&text.slice(..7)));
+
+    let mut modified = text.clone();
+    unsafe {
+        modified.as_bytes_mut();
+    }
+    assert!(!modified.same_buffer(&text));
+    assert!(!modified.same_buffer(&modified));
+}
+
+#[test]
+fn test_try_join_adjacent_shared() {
+    let text = ByteStr::from("GET /index.html HTTP/1.1\r\nHost: example.com\r\n\r\n");
+    let (head, rest) = text.clone().split_at(4);
+    let (path, tail) = rest.split_at(11);
+
+    let joined = ByteStr::try_join_adjacent(&head, &path).unwrap();
+    assert_eq!(joined, "GET /index.html");
+    assert_eq!(joined.as_ptr(), text.as_ptr());
+    assert_eq!(joined.capacity(), text.capacity());
+    assert!(joined.same_buffer(&text));
+    assert_eq!(joined.offset_in(&text), Some(0..15));
+
+    // Joined views can be joined and sliced further.
+    let whole = ByteStr::try_join_adjacent(&joined, &tail).unwrap();
+    assert_eq!(whole, text);
+    assert_eq!(whole.slice(4..15).offset_in(&text), Some(4..15));
+    let again = ByteStr::try_join_adjacent(&whole.slice(..4), &path).unwrap();
+    assert_eq!(again, "GET /index.html");
+
+    assert!(ByteStr::try_join_adjacent(&path, &head).is_none());
+    assert!(ByteStr::try_join_adjacent(&head, &tail).is_none());
+}
+
+#[test]
+fn test_try_join_adjacent_static_and_empty() {
+    const TEXT: &str = "static text split in two";
+    let text = ByteStr::from_static(TEXT);
+    let (first, second) = text.split_at(6);
+    let joined = ByteStr::try_join_adjacent(&first, &second).unwrap();
+    assert!(joined.is_static());
+    assert_eq!(joined, TEXT);
+
+    // Adjacent in memory, but not known to be views of one buffer.
+    let left = ByteStr::from_static(&TEXT[..6]);
+    let right = ByteStr::from_static(&TEXT[6..]);
+    assert!(ByteStr::try_join_adjacent(&left, &right).is_none());
+
+    let empty = ByteStr::new();
+    assert!(ByteStr::ptr_eq(
+        &ByteStr::try_join_adjacent(&empty, &second).unwrap(),
+        &second
+    ));
+    assert!(ByteStr::ptr_eq(
+        &ByteStr::try_join_adjacent(&first, &empty).unwrap(),
+        &first
+    ));
+}
+
+#[test]
+fn test_try_join_adjacent_inline() {
+    let text = ByteStr::from("key: value");
+    let (key, rest) = text.split_at(3);
+    let (colon, value) = rest.split_at(2);
+
+    // Inline slices of one string are only the same buffer by content, so they
+    // are not joined.
+    assert!(key.same_buffer(&colon));
+    assert!(key.same_buffer(&ByteStr::from("key: value").slice(3..5)));
+    assert!(ByteStr::try_join_adjacent(&key, &colon).is_none());
+    assert!(ByteStr::try_join_adjacent(&colon, &value).is_none());
+    assert_eq!(
+        ByteStr::try_join_adjacent(&key, &ByteStr::new()).unwrap(),
+        key
+    );
+}
+
+#[test]
+fn test_spanned() {
+    let source = ByteStr::from_static("x = 1; y = 22; x = 333;");
+    let assignments: Vec<Spanned> = source
+        .split(';')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.spanned_in(&source).unwrap())
+        .collect();
+    let spans: Vec<_> = assignments.iter().map(Spanned::span).collect();
+    assert_eq!(spans, [0..5, 7..13, 15..22]);
+
+    let names: Vec<Spanned> = assignments
+        .into_iter()
+        .map(|a| a.map(|s| s.split(' ').next().unwrap()))
+        .collect();
+    assert_eq!(names[0], names[2]);
+    assert_ne!(names[0].span(), names[2].span());
+    assert_eq!(names[1], "y");
+    assert_eq!(format!("{}", names[1]), "y");
+
+    let mut set = alloc::collections::BTreeSet::new();
+    assert!(set.insert(names[0].clone()));
+    assert!(!set.insert(names[2].clone()));
+
+    let (name, span) = names[2].clone().into_parts();
+    assert_eq!((name.as_str(), span), ("x", 15..22));
+    assert!(ByteStr::from("y").spanned_in(&source).is_none());
+}
+
+// Parser combinator related tests
+#[test]
+fn test_into_chars() {
+    let mut chars = ByteStr::from(String::from("añb€")).into_chars();
+    assert_eq!(chars.size_hint(), (2, Some(7)));
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.next_back(), Some('€'));
+    assert_eq!(chars.as_byte_str(), "ñb");
+    assert_eq!(chars.collect::<String>(), "ñb");
+
+    let mut indices = ByteStr::from("añb€").into_char_indices();
+    assert_eq!(indices.next_back(), Some((4, '€')));
+    assert_eq!(indices.next(), Some((0, 'a')));
+    assert_eq!(indices.next(), Some((1, 'ñ')));
+    assert_eq!(indices.next_back(), Some((3, 'b')));
+    assert_eq!(indices.next(), None);
+    assert_eq!(indices.next_back(), None);
+    assert!(indices.as_byte_str().is_empty());
+}
+
+#[cfg(feature = "nom")]
+#[test]
+fn test_nom_byte_str_input() {
+    use nom::bytes::complete::{tag, tag_no_case, take_until, take_while1};
+    use nom::character::complete::{alpha1, digit1, space1};
+    use nom::combinator::recognize;
+    use nom::sequence::separated_pair;
+    use nom::{IResult, Parser};
+
+    fn header(input: ByteStr) -> IResult<ByteStr, (ByteStr, ByteStr)> {
+        separated_pair(
+            take_while1(|c: char| c.is_ascii_alphanumeric() || c == '-'),
+            tag(": "),
+            digit1,
+        )
+        .parse(input)
+    }
+
+    let source = ByteStr::from_static("Content-Length: 12345 bytes of ünïcödé payload");
+    let (rest, (name, value)) = header(source.clone()).unwrap();
+    assert_eq!((name.as_str(), value.as_str()), ("Content-Length", "12345"));
+    assert_eq!(name.offset_in(&source), Some(0..14));
+    assert_eq!(value.offset_in(&source), Some(16..21));
+
+    let (rest, unit) = recognize((space1::<_, nom::error::Error<_>>, alpha1, tag(" of")))
+        .parse(rest)
+        .unwrap();
+    assert_eq!(unit, " bytes of");
+    assert_eq!(unit.offset_in(&source), Some(21..30));
+
+    let (rest, text) = take_until::<_, _, nom::error::Error<_>>(" payload")
+        .parse(rest)
+        .unwrap();
+    assert_eq!(text, " ünïcödé");
+    assert_eq!(rest, " payload");
+
+    let (_, matched) = tag_no_case::<_, _, nom::error::Error<_>>("content-LENGTH")
+        .parse(source.clone())
+        .unwrap();
+    assert!(matched.is_subslice_of(&source));
+
+    let err = alpha1::<_, nom::error::Error<_>>(ByteStr::from("123")).unwrap_err();
+    assert!(matches!(err, nom::Err::Error(e) if e.input == "123"));
+}
+
+#[cfg(feature = "nom")]
+#[test]
+fn test_nom_located_input() {
+    use crate::Located;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{alpha1, digit1};
+    use nom::combinator::recognize;
+    use nom::sequence::preceded;
+    use nom::{IResult, Parser};
+
+    fn binding(input: Located) -> IResult<Located, (Located, Located)> {
+        (preceded(tag("let "), alpha1), preceded(tag(" = "), digit1)).parse(input)
+    }
+
+    let source = ByteStr::from_static("let answer = 42;");
+    let (rest, (name, value)) = binding(Located::new(source.clone())).unwrap();
+    assert_eq!((name.span(), value.span()), (4..10, 13..15));
+    assert_eq!(name.as_str(), &source[name.span()]);
+    assert_eq!(value.get().offset_in(&source), Some(13..15));
+    assert_eq!((rest.offset(), rest.as_str()), (15, ";"));
+
+    let input = Located::with_offset(ByteStr::from("x = 7"), 100);
+    let (_, whole) = recognize((alpha1::<_, nom::error::Error<_>>, tag(" = "), digit1))
+        .parse(input)
+        .unwrap();
+    assert_eq!(whole.into_spanned().span(), 100..105);
+}
+
+#[cfg(feature = "nom")]
+#[test]
+fn test_nom_owned_short_and_long_inputs() {
+    use crate::Located;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{alpha1, digit1};
+    use nom::sequence::preceded;
+    use nom::{IResult, Offset, Parser};
+
+    fn binding(input: ByteStr) -> IResult<ByteStr, (ByteStr, ByteStr)> {
+        (preceded(tag("let "), alpha1), preceded(tag(" = "), digit1)).parse(input)
+    }
+
+    fn located_binding(input: Located) -> IResult<Located, (Located, Located)> {
+        (preceded(tag("let "), alpha1), preceded(tag(" = "), digit1)).parse(input)
+    }
+
+    let short = ByteStr::from("let answer = 42;");
+    let long = ByteStr::from(format!("let answer = 42; {}", "// comment ".repeat(4)));
+    assert!(is_inline(&short));
+    assert!(!is_inline(&long));
+
+    for source in [short, long] {
+        let (rest, (name, value)) = binding(source.clone()).unwrap();
+        assert_eq!((name.as_str(), value.as_str()), ("answer", "42"));
+        assert_eq!(name.offset_in(&source), Some(4..10));
+        assert_eq!(value.offset_in(&source), Some(13..15));
+        assert_eq!(source.offset(&rest), 15);
+        assert!(rest.is_subslice_of(&source));
+        assert!(value.same_buffer(&source));
+
+        let (rest, (name, value)) = located_binding(Located::new(source.clone())).unwrap();
+        assert_eq!((name.span(), value.span()), (4..10, 13..15));
+        assert_eq!(value.get().offset_in(&source), Some(13..15));
+        assert_eq!(rest.offset(), 15);
+    }
+}
+
+#[cfg(feature = "nom")]
+#[test]
+#[should_panic(expected = "the second input is not a slice of the first")]
+fn test_nom_offset_rejects_unrelated_input() {
+    use nom::Offset;
+
+    let _ = ByteStr::from("abc").offset(&ByteStr::from("abcdef"));
+}
+
+#[cfg(feature = "nom")]
+#[test]
+#[should_panic(expected = "the second input starts before the first")]
+fn test_nom_located_offset_rejects_earlier_input() {
+    use crate::Located;
+    use nom::Offset;
+
+    let input = Located::with_offset(ByteStr::from("abc"), 10);
+    let _ = Offset::offset(&input, &Located::new(ByteStr::from("abc")));
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+fn test_winnow_byte_str_stream() {
+    use winnow::ascii::{alpha1, digit1, space1};
+    use winnow::combinator::separated_pair;
+    use winnow::error::ContextError;
+    use winnow::prelude::*;
+    use winnow::token::{take_until, take_while};
+
+    fn header(input: &mut ByteStr) -> ModalResult<(ByteStr, ByteStr)> {
+        separated_pair(
+            take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '-'),
+            ": ",
+            digit1,
+        )
+        .parse_next(input)
+    }
+
+    let source = ByteStr::from_static("Content-Length: 12345 bytes of ünïcödé payload");
+    let mut input = source.clone();
+    let (name, value) = header(&mut input).unwrap();
+    assert_eq!((name.as_str(), value.as_str()), ("Content-Length", "12345"));
+    assert_eq!(name.offset_in(&source), Some(0..14));
+    assert_eq!(value.offset_in(&source), Some(16..21));
+
+    let unit = (space1::<_, ContextError>, alpha1, " of")
+        .take()
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!(unit, " bytes of");
+    assert_eq!(unit.offset_in(&source), Some(21..30));
+
+    let text = take_until::<_, _, ContextError>(0.., " payload")
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!(text, " ünïcödé");
+    assert_eq!(input, " payload");
+
+    let number: u32 = digit1::<_, ContextError>
+        .parse_to()
+        .parse(ByteStr::from("8080"))
+        .unwrap();
+    assert_eq!(number, 8080);
+    assert!(
+        alpha1::<_, ContextError>
+            .parse(ByteStr::from("abc1"))
+            .is_err()
+    );
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+fn test_winnow_located_stream() {
+    use crate::Located;
+    use winnow::ascii::{alpha1, digit1};
+    use winnow::combinator::{alt, preceded};
+    use winnow::error::ContextError;
+    use winnow::prelude::*;
+
+    fn binding(input: &mut Located) -> ModalResult<(Located, Located)> {
+        (preceded("let ", alpha1), preceded(" = ", digit1)).parse_next(input)
+    }
+
+    let source = ByteStr::from_static("let answer = 42;");
+    let mut input = Located::new(source.clone());
+    let (name, value) = binding(&mut input).unwrap();
+    assert_eq!((name.span(), value.span()), (4..10, 13..15));
+    assert_eq!(value.get().offset_in(&source), Some(13..15));
+    assert_eq!((input.offset(), input.as_str()), (15, ";"));
+
+    // Backtracking restores the offset along with the input.
+    let mut input = Located::with_offset(ByteStr::from("x = 7"), 100);
+    let (word, span) = alt((digit1::<_, ContextError>, alpha1))
+        .with_span()
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!((word.span(), span), (100..101, 100..101));
+    assert_eq!(input.offset(), 101);
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+fn test_winnow_owned_short_and_long_inputs() {
+    use crate::Located;
+    use winnow::ascii::{alpha1, digit1};
+    use winnow::combinator::preceded;
+    use winnow::prelude::*;
+    use winnow::stream::Offset;
+
+    fn binding(input: &mut ByteStr) -> ModalResult<(ByteStr, ByteStr)> {
+        (preceded("let ", alpha1), preceded(" = ", digit1)).parse_next(input)
+    }
+
+    fn located_binding(input: &mut Located) -> ModalResult<(Located, Located)> {
+        (preceded("let ", alpha1), preceded(" = ", digit1)).parse_next(input)
+    }
+
+    let short = ByteStr::from("let answer = 42;");
+    let long = ByteStr::from(format!("let answer = 42; {}", "// comment ".repeat(4)));
+    assert!(is_inline(&short));
+    assert!(!is_inline(&long));
+
+    for source in [short, long] {
+        let mut input = source.clone();
+        let (name, value) = binding(&mut input).unwrap();
+        assert_eq!((name.as_str(), value.as_str()), ("answer", "42"));
+        assert_eq!(name.offset_in(&source), Some(4..10));
+        assert_eq!(value.offset_in(&source), Some(13..15));
+        assert_eq!(input.offset_from(&source), 15);
+        assert!(input.is_subslice_of(&source));
+        assert!(value.same_buffer(&source));
+
+        // The README example, on both inputs.
+        let mut input = Located::new(source.clone());
+        let (name, value) = located_binding(&mut input).unwrap();
+        assert_eq!((name.as_str(), name.span()), ("answer", 4..10));
+        assert_eq!((value.as_str(), value.span()), ("42", 13..15));
+        assert_eq!(value.get().offset_in(&source), Some(13..15));
+        assert_eq!(input.offset(), 15);
+    }
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+#[should_panic(expected = "the input is not a slice of the start")]
+fn test_winnow_offset_rejects_unrelated_input() {
+    use winnow::stream::Offset;
+
+    let _ = ByteStr::from("abcdef").offset_from(&ByteStr::from("abc"));
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+#[should_panic(expected = "the input starts before the start")]
+fn test_winnow_located_offset_rejects_earlier_input() {
+    use crate::Located;
+    use winnow::stream::Offset;
+
+    let input = Located::new(ByteStr::from("abc"));
+    let _ = input.offset_from(&Located::with_offset(ByteStr::from("abc"), 10));
+}
+
+#[cfg(any(feature = "nom", feature = "winnow"))]
+#[test]
+fn test_located_eq_and_hash_ignore_offset() {
+    use crate::Located;
+
+    let at_start = Located::new(ByteStr::from("token"));
+    let elsewhere = Located::with_offset(ByteStr::from("token"), 42);
+    assert_eq!(at_start, elsewhere);
+    assert_eq!(std_hash(&at_start), std_hash(&elsewhere));
+    assert_eq!(std_hash(&at_start), std_hash(at_start.get()));
+    assert_ne!(at_start, Located::new(ByteStr::from("other")));
+
+    // Consistent with `Spanned`, which also ignores its span.
+    assert_eq!(
+        at_start.clone().into_spanned(),
+        elsewhere.clone().into_spanned()
+    );
+    assert_ne!(at_start.span(), elsewhere.span());
+}
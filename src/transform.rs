@@ -0,0 +1,249 @@
+use alloc::string::String;
+
+use crate::ByteStr;
+use crate::pattern::{DoubleEndedPattern, Pattern, Searcher};
+
+// Unlike their `str` counterparts, these methods return a clone of the
+// `ByteStr` when there is nothing to change, and only allocate otherwise.
+impl ByteStr {
+    /// Returns a copy of the string with every ASCII letter mapped to its
+    /// lowercase equivalent. Non-ASCII characters are left unchanged.
+    ///
+    /// If the string contains no uppercase ASCII letter, this returns a clone
+    /// sharing the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let header = ByteStr::from_static("content-type");
+    /// assert!(ByteStr::ptr_eq(&header.to_ascii_lowercase(), &header));
+    /// assert_eq!(ByteStr::from("Content-Type").to_ascii_lowercase(), "content-type");
+    /// ```
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> Self {
+        if !self.bytes().any(|b| b.is_ascii_uppercase()) {
+            return self.clone();
+        }
+        let mut lower = String::from(self.as_str());
+        lower.make_ascii_lowercase();
+        Self::from(lower)
+    }
+
+    /// Returns a copy of the string with every ASCII letter mapped to its
+    /// uppercase equivalent. Non-ASCII characters are left unchanged.
+    ///
+    /// If the string contains no lowercase ASCII letter, this returns a clone
+    /// sharing the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let method = ByteStr::from_static("GET");
+    /// assert!(ByteStr::ptr_eq(&method.to_ascii_uppercase(), &method));
+    /// assert_eq!(ByteStr::from("post").to_ascii_uppercase(), "POST");
+    /// ```
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> Self {
+        if !self.bytes().any(|b| b.is_ascii_lowercase()) {
+            return self.clone();
+        }
+        let mut upper = String::from(self.as_str());
+        upper.make_ascii_uppercase();
+        Self::from(upper)
+    }
+
+    /// Returns the lowercase equivalent of the string, as defined by
+    /// [`str::to_lowercase`].
+    ///
+    /// If every character already is lowercase, this returns a clone sharing
+    /// the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from_static("straße");
+    /// assert!(ByteStr::ptr_eq(&s.to_lowercase(), &s));
+    /// assert_eq!(ByteStr::from("ὈΔΥΣΣΕΎΣ").to_lowercase(), "ὀδυσσεύς");
+    /// ```
+    #[must_use]
+    pub fn to_lowercase(&self) -> Self {
+        if self.chars().all(|c| c.to_lowercase().eq([c])) {
+            return self.clone();
+        }
+        Self::from(self.as_str().to_lowercase())
+    }
+
+    /// Returns the uppercase equivalent of the string, as defined by
+    /// [`str::to_uppercase`].
+    ///
+    /// If every character already is uppercase, this returns a clone sharing
+    /// the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from_static("HELLO, WORLD!");
+    /// assert!(ByteStr::ptr_eq(&s.to_uppercase(), &s));
+    /// assert_eq!(ByteStr::from("straße").to_uppercase(), "STRASSE");
+    /// ```
+    #[must_use]
+    pub fn to_uppercase(&self) -> Self {
+        if self.chars().all(|c| c.to_uppercase().eq([c])) {
+            return self.clone();
+        }
+        Self::from(self.as_str().to_uppercase())
+    }
+
+    /// Replaces all matches of a pattern with another string.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`]. If nothing
+    /// matches, or every match already equals `to`, this returns a clone sharing
+    /// the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let path = ByteStr::from_static("/usr/local/bin");
+    /// assert!(ByteStr::ptr_eq(&path.replace('\\', "/"), &path));
+    /// assert_eq!(path.replace('/', "\\"), "\\usr\\local\\bin");
+    /// ```
+    #[must_use]
+    pub fn replace<P: Pattern>(&self, from: P, to: &str) -> Self {
+        self.replace_matches(from, to, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of a pattern with another string.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`]. If nothing
+    /// is replaced, or every replaced match already equals `to`, this returns a
+    /// clone sharing the same buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from_static("a-b-c-d");
+    /// assert_eq!(s.replacen('-', "+", 2), "a+b+c-d");
+    /// assert!(ByteStr::ptr_eq(&s.replacen('-', "+", 0), &s));
+    /// ```
+    #[must_use]
+    pub fn replacen<P: Pattern>(&self, from: P, to: &str, count: usize) -> Self {
+        self.replace_matches(from, to, count)
+    }
+
+    /// Returns a zero-copy slice with all prefixes and suffixes that match a
+    /// pattern repeatedly removed.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`], except `&str`,
+    /// like [`str::trim_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("--name--");
+    /// assert_eq!(s.trim_matches('-'), "name");
+    /// assert_eq!(s.trim_matches(|c: char| !c.is_alphabetic()), "name");
+    /// ```
+    #[must_use]
+    pub fn trim_matches<P: DoubleEndedPattern>(&self, mut pat: P) -> Self {
+        let trimmed = trim_end_of(trim_start_of(self.as_str(), &mut pat), &mut pat);
+        self.slice_ref(trimmed)
+    }
+
+    /// Returns a zero-copy slice with all prefixes that match a pattern
+    /// repeatedly removed.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("0x0x1F");
+    /// assert_eq!(s.trim_start_matches("0x"), "1F");
+    /// ```
+    #[must_use]
+    pub fn trim_start_matches<P: Pattern>(&self, mut pat: P) -> Self {
+        self.slice_ref(trim_start_of(self.as_str(), &mut pat))
+    }
+
+    /// Returns a zero-copy slice with all suffixes that match a pattern
+    /// repeatedly removed.
+    ///
+    /// The pattern accepts the same types as [`ByteStr::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("1.500");
+    /// assert_eq!(s.trim_end_matches('0'), "1.5");
+    /// ```
+    #[must_use]
+    pub fn trim_end_matches<P: Pattern>(&self, mut pat: P) -> Self {
+        self.slice_ref(trim_end_of(self.as_str(), &mut pat))
+    }
+
+    fn replace_matches<P: Pattern>(&self, from: P, to: &str, count: usize) -> Self {
+        let s = self.as_str();
+        let mut searcher = Searcher::new(from, s.len());
+        let mut replaced: Option<String> = None;
+        let mut last = 0;
+        for _ in 0..count {
+            let Some((start, end)) = searcher.next_match(s) else {
+                break;
+            };
+            // Until something actually changes, matches equal to `to` are
+            // left in place and copied over with the rest.
+            if replaced.is_none() && &s[start..end] == to {
+                continue;
+            }
+            let out = replaced.get_or_insert_with(|| String::with_capacity(s.len()));
+            out.push_str(&s[last..start]);
+            out.push_str(to);
+            last = end;
+        }
+
+        let Some(mut out) = replaced else {
+            return self.clone();
+        };
+        out.push_str(&s[last..]);
+        Self::from(out)
+    }
+}
+
+fn trim_start_of<'a, P: Pattern>(mut s: &'a str, pat: &mut P) -> &'a str {
+    while let Some(rest) = pat.strip_prefix_of(s) {
+        // An empty match would never make progress.
+        if rest.len() == s.len() {
+            break;
+        }
+        s = rest;
+    }
+    s
+}
+
+fn trim_end_of<'a, P: Pattern>(mut s: &'a str, pat: &mut P) -> &'a str {
+    while let Some(rest) = pat.strip_suffix_of(s) {
+        if rest.len() == s.len() {
+            break;
+        }
+        s = rest;
+    }
+    s
+}
@@ -0,0 +1,154 @@
+use alloc::string::String;
+use core::fmt::{self, Alignment, Write};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::ByteStr;
+
+// Widths are the number of columns a terminal uses to display the text, as
+// given by Unicode Standard Annex #11: East Asian wide characters and most
+// emoji take two columns, combining marks none.
+impl ByteStr {
+    /// Returns the number of terminal columns needed to display the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// assert_eq!(ByteStr::from("hello").display_width(), 5);
+    /// assert_eq!(ByteStr::from("你好").display_width(), 4);
+    /// assert_eq!(ByteStr::from("e\u{301}").display_width(), 1);
+    /// ```
+    #[must_use]
+    pub fn display_width(&self) -> usize {
+        self.as_str().width()
+    }
+
+    /// Returns the longest prefix of the string that fits in `cols` terminal
+    /// columns, as a zero-copy slice.
+    ///
+    /// A wide character that would only partially fit is left out, and
+    /// combining marks stay with the character they belong to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("日本語テキスト");
+    /// assert_eq!(s.truncate_to_width(5), "日本");
+    /// assert_eq!(s.truncate_to_width(6), "日本語");
+    /// ```
+    #[must_use]
+    pub fn truncate_to_width(&self, cols: usize) -> Self {
+        self.slice_ref(prefix_within_width(self.as_str(), cols))
+    }
+
+    /// Shortens the string to fit in `cols` terminal columns, ending it with an
+    /// ellipsis (`…`) if anything was cut off.
+    ///
+    /// A string that already fits is returned as a clone sharing the same
+    /// buffer; otherwise a new string is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytestr::ByteStr;
+    ///
+    /// let s = ByteStr::from("bytestr-0.3.1.crate");
+    /// assert_eq!(s.truncate_to_width_with_ellipsis(10), "bytestr-0…");
+    /// assert_eq!(s.truncate_to_width_with_ellipsis(30), s);
+    /// ```
+    #[must_use]
+    pub fn truncate_to_width_with_ellipsis(&self, cols: usize) -> Self {
+        const ELLIPSIS: char = '…';
+
+        if self.display_width() <= cols {
+            return self.clone();
+        }
+        let Some(cols) = cols.checked_sub(ELLIPSIS.width().unwrap_or(1)) else {
+            return Self::new();
+        };
+        let mut truncated = String::from(prefix_within_width(self.as_str(), cols));
+        truncated.push(ELLIPSIS);
+        Self::from(truncated)
+    }
+
+    /// Returns an adapter that pads and truncates the string by terminal
+    /// columns when formatted, rather than by `char`s.
+    ///
+    /// See [`Aligned`] for details.
+    #[must_use]
+    pub const fn aligned(&self) -> Aligned<'_> {
+        Aligned(self)
+    }
+}
+
+/// A [`Display`](fmt::Display) adapter that measures a string in terminal
+/// columns, created by [`ByteStr::aligned`].
+///
+/// Formatting a `str` pads it to the requested width by counting `char`s,
+/// which misaligns tables holding East Asian text or emoji. This adapter
+/// honors the same width, fill, alignment and precision options, but counts
+/// columns: the width pads the string to that many columns, and the precision
+/// truncates it to at most that many columns.
+///
+/// # Examples
+///
+/// ```
+/// use bytestr::ByteStr;
+///
+/// let name = ByteStr::from("東京");
+/// assert_eq!(format!("[{:<6}]", name.aligned()), "[東京  ]");
+/// assert_eq!(format!("[{:*^8}]", name.aligned()), "[**東京**]");
+/// assert_eq!(format!("[{:>5.3}]", name.aligned()), "[   東]");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Aligned<'a>(&'a ByteStr);
+
+impl fmt::Display for Aligned<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.0.as_str();
+        let s = f.precision().map_or(s, |cols| prefix_within_width(s, cols));
+        let padding = f.width().unwrap_or(0).saturating_sub(s.width());
+        let (before, after) = match f.align() {
+            Some(Alignment::Left) | None => (0, padding),
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(s)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the longest prefix of `s` that is at most `cols` columns wide.
+fn prefix_within_width(s: &str, cols: usize) -> &str {
+    // Adding up the widths of the chars is exact for most text. Sequences
+    // whose width differs from the sum, such as emoji with a variation
+    // selector, are corrected by measuring the prefix as a whole.
+    let mut width = 0;
+    let mut end = s.len();
+    for (index, c) in s.char_indices() {
+        width += c.width().unwrap_or(0);
+        if width > cols {
+            end = index;
+            break;
+        }
+    }
+
+    let mut prefix = &s[..end];
+    while prefix.width() > cols {
+        let mut chars = prefix.chars();
+        chars.next_back();
+        prefix = chars.as_str();
+    }
+    prefix
+}
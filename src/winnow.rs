@@ -0,0 +1,230 @@
+use core::fmt;
+
+use winnow::stream::{
+    AsBStr, Compare, CompareResult, FindSlice, Location, Needed, Offset, ParseSlice, SliceLen,
+    Stream, StreamIsPartial, UpdateSlice,
+};
+
+use crate::iter::IntoCharIndices;
+use crate::{ByteStr, Located};
+
+// `ByteStr` is parsed like `&str`, by `char`s, and every slice taken from it is
+// a view of the input, which `ByteStr::offset_in` can locate, even for inline
+// inputs whose slices are copies. A checkpoint is a clone of the input, and
+// offsets are found the same way, so they work for inline inputs as well.
+impl Stream for ByteStr {
+    type Token = char;
+    type Slice = Self;
+    type IterOffsets = IntoCharIndices;
+    type Checkpoint = Self;
+
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.clone().into_char_indices()
+    }
+
+    fn eof_offset(&self) -> usize {
+        self.len()
+    }
+
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let c = self.peek_token()?;
+        *self = self.slice(c.len_utf8()..);
+        Some(c)
+    }
+
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.chars().next()
+    }
+
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.as_str().offset_for(predicate)
+    }
+
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.as_str().offset_at(tokens)
+    }
+
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        let slice = self.slice(..offset);
+        *self = self.slice(offset..);
+        slice
+    }
+
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.slice(..offset)
+    }
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.clone()
+    }
+
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.clone_from(checkpoint);
+    }
+
+    fn trace(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#?}", self.as_str())
+    }
+}
+
+// Panics if `self` is not a slice of `start`, which winnow never passes.
+impl Offset for ByteStr {
+    fn offset_from(&self, start: &Self) -> usize {
+        self.offset_in(start)
+            .expect("the input is not a slice of the start")
+            .start
+    }
+}
+
+impl UpdateSlice for ByteStr {
+    fn update_slice(self, inner: Self::Slice) -> Self {
+        inner
+    }
+}
+
+// Tokens taken from a `Located` input are `Located` as well, with the offset
+// of the slice added to that of the input.
+impl Stream for Located {
+    type Token = char;
+    type Slice = Self;
+    type IterOffsets = IntoCharIndices;
+    type Checkpoint = Self;
+
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.get().clone().into_char_indices()
+    }
+
+    fn eof_offset(&self) -> usize {
+        self.as_str().len()
+    }
+
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let c = self.peek_token()?;
+        *self = self.slice(c.len_utf8()..self.eof_offset());
+        Some(c)
+    }
+
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.as_str().chars().next()
+    }
+
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.as_str().offset_for(predicate)
+    }
+
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.as_str().offset_at(tokens)
+    }
+
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        let slice = self.slice(0..offset);
+        *self = self.slice(offset..self.eof_offset());
+        slice
+    }
+
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.slice(0..offset)
+    }
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.clone()
+    }
+
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.clone_from(checkpoint);
+    }
+
+    fn trace(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:#?}", self.offset(), self.as_str())
+    }
+}
+
+// Panics if `self` starts before `start`, which winnow never passes.
+impl Offset for Located {
+    fn offset_from(&self, start: &Self) -> usize {
+        self.offset()
+            .checked_sub(start.offset())
+            .expect("the input starts before the start")
+    }
+}
+
+impl UpdateSlice for Located {
+    fn update_slice(self, inner: Self::Slice) -> Self {
+        inner
+    }
+}
+
+impl Location for Located {
+    fn previous_token_end(&self) -> usize {
+        self.offset()
+    }
+
+    fn current_token_start(&self) -> usize {
+        self.offset()
+    }
+}
+
+// Implements the traits that only look at the contents of the input by
+// delegating to their `&str` implementations.
+macro_rules! impl_str_traits {
+    ($($ty:ty),*) => {$(
+        impl SliceLen for $ty {
+            fn slice_len(&self) -> usize {
+                self.as_str().len()
+            }
+        }
+
+        impl StreamIsPartial for $ty {
+            type PartialState = ();
+
+            fn complete(&mut self) -> Self::PartialState {}
+
+            fn restore_partial(&mut self, _state: Self::PartialState) {}
+
+            fn is_partial_supported() -> bool {
+                false
+            }
+        }
+
+        impl AsBStr for $ty {
+            fn as_bstr(&self) -> &[u8] {
+                self.as_str().as_bytes()
+            }
+        }
+
+        impl<T> Compare<T> for $ty
+        where
+            for<'a> &'a str: Compare<T>,
+        {
+            fn compare(&self, t: T) -> CompareResult {
+                self.as_str().compare(t)
+            }
+        }
+
+        impl<T> FindSlice<T> for $ty
+        where
+            for<'a> &'a str: FindSlice<T>,
+        {
+            fn find_slice(&self, substr: T) -> Option<core::ops::Range<usize>> {
+                self.as_str().find_slice(substr)
+            }
+        }
+
+        impl<R> ParseSlice<R> for $ty
+        where
+            for<'a> &'a str: ParseSlice<R>,
+        {
+            fn parse_slice(&self) -> Option<R> {
+                self.as_str().parse_slice()
+            }
+        }
+    )*};
+}
+
+impl_str_traits!(ByteStr, Located);